@@ -0,0 +1,152 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Heap usage of a piece of code on the thread that ran it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    /// Total bytes requested, including reallocations.
+    pub bytes: u64,
+    /// The most bytes live at once, over what was live before.
+    pub peak: u64,
+}
+
+/// Passes everything on to the system allocator, counting it for `measure`.
+/// Installed as the global allocator by the `count-allocations` feature.
+pub struct CountingAllocator;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Clone, Copy, Default)]
+struct Counts {
+    allocations: u64,
+    bytes: u64,
+    // memory freed here may have been allocated before measuring started,
+    // so this can go negative
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTS: Cell<Counts> = const { Cell::new(Counts { allocations: 0, bytes: 0, live: 0, peak: 0 }) };
+}
+
+fn count(allocated: usize, freed: usize) {
+    // the thread local is gone while a thread shuts down
+    let _ = COUNTS.try_with(|counts| {
+        let mut c = counts.get();
+        if allocated > 0 {
+            c.allocations += 1;
+            c.bytes += allocated as u64;
+        }
+        c.live += allocated as i64 - freed as i64;
+        c.peak = c.peak.max(c.live);
+        counts.set(c);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            count(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            count(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        count(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            count(new_size, layout.size());
+        }
+        new
+    }
+}
+
+/// Whether allocations are being counted at all.
+pub fn is_enabled() -> bool {
+    cfg!(feature = "count-allocations")
+}
+
+/// Runs `f`, returning its heap usage if allocations are being counted. Only
+/// allocations made on the calling thread are seen.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    let (value, stats) = counted(f);
+    (value, is_enabled().then_some(stats))
+}
+
+fn counted<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let outer = COUNTS.with(|counts| counts.replace(Counts::default()));
+    let value = f();
+    let inner = COUNTS.with(|counts| {
+        let inner = counts.get();
+        counts.set(Counts {
+            allocations: outer.allocations + inner.allocations,
+            bytes: outer.bytes + inner.bytes,
+            live: outer.live + inner.live,
+            peak: outer.peak.max(outer.live + inner.peak),
+        });
+        inner
+    });
+    let stats = AllocStats { allocations: inner.allocations, bytes: inner.bytes, peak: inner.peak.max(0) as u64 };
+    (value, stats)
+}
+
+/// Formats a byte count for display, such as "532 B", "12.3 KiB" or "4.0 MiB".
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counted() {
+        let small = Layout::from_size_align(100, 8).unwrap();
+        let large = Layout::from_size_align(1000, 8).unwrap();
+        let ((), stats) = counted(|| unsafe {
+            let a = CountingAllocator.alloc(small);
+            let ((), inner) = counted(|| {
+                let b = CountingAllocator.alloc(large);
+                CountingAllocator.dealloc(b, large);
+            });
+            assert_eq!(inner, AllocStats { allocations: 1, bytes: 1000, peak: 1000 });
+            let a = CountingAllocator.realloc(a, small, 300);
+            CountingAllocator.dealloc(a, Layout::from_size_align(300, 8).unwrap());
+        });
+        assert_eq!(stats, AllocStats { allocations: 3, bytes: 1400, peak: 1100 });
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(532), "532 B");
+        assert_eq!(format_bytes(12_595), "12.3 KiB");
+        assert_eq!(format_bytes(4 * 1024 * 1024), "4.0 MiB");
+    }
+}
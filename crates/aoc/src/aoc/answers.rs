@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use super::{Answer, Part};
+
+/// Expected answers for the bundled inputs, one `day part answer` line each.
+/// Blank lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u32, Part), String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl KnownAnswers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        Self::parse(&contents).map_err(|e| format!("{}:{e}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [day, part, answer] = fields[..] else {
+                return Err(format!("{}: expected 'day part answer', got '{line}'", i + 1));
+            };
+            let day = day.parse::<u32>().map_err(|_| format!("{}: invalid day '{day}'", i + 1))?;
+            let part = part.parse::<u32>().ok()
+                .and_then(Part::from_number)
+                .ok_or(format!("{}: invalid part '{part}'", i + 1))?;
+            if answers.insert((day, part), answer.to_string()).is_some() {
+                return Err(format!("{}: day {day} {part} is listed twice", i + 1));
+            }
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn check(&self, day: u32, part: Part, answer: &Answer) -> Check {
+        match self.get(day, part) {
+            Some(expected) if expected == answer.as_str() => Check::Pass,
+            Some(expected) => Check::Fail { expected: expected.to_string() },
+            None => Check::Missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = KnownAnswers::parse("# day part answer\n1 1 11\n\n1 2 31\n").unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("11"));
+        assert_eq!(answers.get(1, Part::Two), Some("31"));
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(KnownAnswers::parse("1 1\n").unwrap_err(), "1: expected 'day part answer', got '1 1'");
+        assert_eq!(KnownAnswers::parse("1 1 5\n1 3 5").unwrap_err(), "2: invalid part '3'");
+        assert_eq!(KnownAnswers::parse("1 1 5\n1 1 6").unwrap_err(), "2: day 1 part_1 is listed twice");
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = KnownAnswers::parse("6 1 41").unwrap();
+        assert_eq!(answers.check(6, Part::One, &Answer::from(41)), Check::Pass);
+        assert_eq!(answers.check(6, Part::One, &Answer::from(42)), Check::Fail { expected: "41".to_string() });
+        assert_eq!(answers.check(6, Part::Two, &Answer::from(6)), Check::Missing);
+    }
+}
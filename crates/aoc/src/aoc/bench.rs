@@ -0,0 +1,125 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Runs thrown away before measuring, to warm caches and the allocator.
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self { warmup: 3, iterations: 10 }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // nearest-rank percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+
+        Self { iterations: n, min: sorted[0], median, p95, mean }
+    }
+}
+
+/// Times `f` over `config.iterations` runs, after `config.warmup` untimed runs.
+pub fn bench<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let samples = (0..config.iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            let result = f();
+            let elapsed = start.elapsed();
+            // dropped outside the timed region
+            black_box(result);
+            elapsed
+        })
+        .collect::<Vec<_>>();
+    Stats::from_samples(&samples)
+}
+
+/// Reads a duration like `1s`, `0.25s` or `500ms`.
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let (number, scale) = match value.strip_suffix("ms") {
+        Some(millis) => (millis, 1e-3),
+        None => (value.strip_suffix('s')?, 1.0),
+    };
+    Duration::try_from_secs_f64(number.parse::<f64>().ok()? * scale).ok()
+}
+
+/// Formats with three significant decimals in the largest unit that keeps the
+/// value above 1, down to whole nanoseconds.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{nanos}ns"),
+        1_000..1_000_000 => format!("{:.3}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.3}ms", nanos as f64 / 1e6),
+        _ => format!("{:.3}s", nanos as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = [ms(5), ms(1), ms(3), ms(2), ms(4)];
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats, Stats { iterations: 5, min: ms(1), median: ms(3), p95: ms(5), mean: ms(3) });
+    }
+
+    #[test]
+    fn test_stats_even_and_p95() {
+        let samples = (1..=20).map(ms).collect::<Vec<_>>();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, ms(19));
+        assert_eq!(stats.mean, Duration::from_micros(10_500));
+    }
+
+    #[test]
+    fn test_bench_runs_warmup_and_iterations() {
+        let mut calls = 0;
+        let stats = bench(&BenchConfig { warmup: 2, iterations: 5 }, || calls += 1);
+        assert_eq!(calls, 7);
+        assert_eq!(stats.iterations, 5);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(532)), "532ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.345µs");
+        assert_eq!(format_duration(Duration::from_nanos(4_000_000)), "4.000ms");
+        assert_eq!(format_duration(Duration::from_millis(1437)), "1.437s");
+    }
+}
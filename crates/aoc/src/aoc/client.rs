@@ -0,0 +1,254 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const YEAR: u32 = 2024;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Session cookie value, copied from a logged-in browser.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides the base URL, e.g. to point at a local stand-in server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Minimum time between two requests to the site, across runs.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "github.com/samsonjj/advent_of_code_2024 by samsonjj";
+/// 2024-12-01 00:00:00 EST, when day 1 unlocked.
+const FIRST_UNLOCK_SECS: u64 = 1_733_029_200;
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    InvalidDay(u32),
+    Locked { day: u32, unlocks_in: Duration },
+    Status { status: u16, body: String },
+    Transport(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(f, "no session token: set {SESSION_VAR} to your adventofcode.com session cookie"),
+            ClientError::InvalidDay(day) => write!(f, "there is no day {day} in {YEAR}"),
+            ClientError::Locked { day, unlocks_in } => {
+                let secs = unlocks_in.as_secs();
+                write!(f, "day {day} is not unlocked yet (unlocks in {}h{:02}m{:02}s)", secs / 3600, secs / 60 % 60, secs % 60)
+            }
+            ClientError::Status { status, body } => write!(f, "server responded with {status}: {}", body.trim()),
+            ClientError::Transport(message) => write!(f, "request failed: {message}"),
+            ClientError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// Talks to adventofcode.com (or a stand-in at another base URL). Everything
+/// it keeps between runs lives under `state_dir`, which should be git-ignored.
+pub struct Client {
+    base_url: String,
+    session: String,
+    state_dir: PathBuf,
+    min_interval: Duration,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(session: impl Into<String>, state_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.into(),
+            state_dir: state_dir.into(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Builds a client from `$AOC_SESSION` and `$AOC_BASE_URL`.
+    pub fn from_env(state_dir: impl Into<PathBuf>) -> Result<Self, ClientError> {
+        let session = std::env::var(SESSION_VAR).map_err(|_| ClientError::MissingSession)?;
+        let mut client = Self::new(session.trim(), state_dir);
+        if let Ok(base_url) = std::env::var(BASE_URL_VAR) {
+            client = client.with_base_url(base_url);
+        }
+        Ok(client)
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn state_dir(&self) -> &Path {
+        &self.state_dir
+    }
+
+    /// Where a day's input is cached; the directory works with `--input-dir`.
+    pub fn input_cache_path(&self, day: u32) -> PathBuf {
+        self.state_dir.join("inputs").join(format!("day_{day}.txt"))
+    }
+
+    /// Returns the day's input, downloading it only if it is not cached yet.
+    pub fn fetch_input(&self, day: u32) -> Result<String, ClientError> {
+        let path = self.input_cache_path(day);
+        if let Ok(input) = std::fs::read_to_string(&path) {
+            return Ok(input);
+        }
+
+        check_unlocked(day, SystemTime::now())?;
+        let input = self.get(&format!("/{YEAR}/day/{day}/input"))?;
+
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(&path, &input)?;
+        Ok(input)
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        self.throttle()?;
+        let request = self.agent.get(&format!("{}{path}", self.base_url));
+        read_response(request.set("Cookie", &format!("session={}", self.session)).call())
+    }
+
+    pub(crate) fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        self.throttle()?;
+        let request = self.agent.post(&format!("{}{path}", self.base_url));
+        read_response(request.set("Cookie", &format!("session={}", self.session)).send_form(form))
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request made by
+    /// any run, then records this request.
+    fn throttle(&self) -> Result<(), ClientError> {
+        let path = self.state_dir.join("last_request");
+        let last = std::fs::read_to_string(&path).ok()
+            .and_then(|s| s.trim().parse::<u64>().ok())
+            .map(|nanos| UNIX_EPOCH + Duration::from_nanos(nanos));
+
+        if let Some(elapsed) = last.and_then(|last| SystemTime::now().duration_since(last).ok())
+            && elapsed < self.min_interval
+        {
+            std::thread::sleep(self.min_interval - elapsed);
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        std::fs::create_dir_all(&self.state_dir)?;
+        // whole nanoseconds, so the interval is never measured as too long
+        std::fs::write(&path, now.as_nanos().to_string())?;
+        Ok(())
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(status, response)) => Err(ClientError::Status {
+            status,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(e) => Err(ClientError::Transport(e.to_string())),
+    }
+}
+
+pub fn unlock_time(day: u32) -> Result<SystemTime, ClientError> {
+    if !(1..=25).contains(&day) {
+        return Err(ClientError::InvalidDay(day));
+    }
+    Ok(UNIX_EPOCH + Duration::from_secs(FIRST_UNLOCK_SECS + (day as u64 - 1) * DAY_SECS))
+}
+
+pub fn check_unlocked(day: u32, now: SystemTime) -> Result<(), ClientError> {
+    match unlock_time(day)?.duration_since(now) {
+        Ok(unlocks_in) if !unlocks_in.is_zero() => Err(ClientError::Locked { day, unlocks_in }),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::test_server::TestServer;
+
+    fn client(server: &TestServer, name: &str) -> Client {
+        let dir = std::env::temp_dir().join(format!("aoc_client_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        Client::new("abc123", dir)
+            .with_base_url(server.url())
+            .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_check_unlocked() {
+        let day_5 = unlock_time(5).unwrap();
+        assert!(check_unlocked(5, day_5).is_ok());
+        assert!(matches!(
+            check_unlocked(5, day_5 - Duration::from_secs(90)),
+            Err(ClientError::Locked { day: 5, unlocks_in }) if unlocks_in == Duration::from_secs(90),
+        ));
+        assert!(check_unlocked(4, day_5 - Duration::from_secs(90)).is_ok());
+        assert!(matches!(check_unlocked(26, day_5), Err(ClientError::InvalidDay(26))));
+    }
+
+    #[test]
+    fn test_fetch_input_is_cached() {
+        let server = TestServer::start(|_| (200, "1 2\n3 4\n".to_string()));
+        let client = client(&server, "cached");
+
+        assert_eq!(client.fetch_input(3).unwrap(), "1 2\n3 4\n");
+        assert_eq!(client.fetch_input(3).unwrap(), "1 2\n3 4\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/3/input "));
+        assert!(requests[0].contains("session=abc123"));
+        assert_eq!(std::fs::read_to_string(client.input_cache_path(3)).unwrap(), "1 2\n3 4\n");
+        std::fs::remove_dir_all(client.state_dir()).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_error_is_not_cached() {
+        let server = TestServer::start(|_| (400, "Please log in to get your puzzle input.".to_string()));
+        let client = client(&server, "error");
+
+        assert!(matches!(client.fetch_input(3), Err(ClientError::Status { status: 400, .. })));
+        assert!(!client.input_cache_path(3).exists());
+        let _ = std::fs::remove_dir_all(client.state_dir());
+    }
+
+    #[test]
+    fn test_refuses_invalid_day() {
+        let server = TestServer::start(|_| (200, String::new()));
+        let client = client(&server, "invalid");
+
+        assert!(matches!(client.fetch_input(0), Err(ClientError::InvalidDay(0))));
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let server = TestServer::start(|_| (200, "input".to_string()));
+        let client = client(&server, "rate_limit").with_min_interval(Duration::from_millis(200));
+
+        // the same clock the throttle uses, so the bound holds exactly
+        let start = SystemTime::now();
+        client.fetch_input(1).unwrap();
+        client.fetch_input(2).unwrap();
+        assert!(SystemTime::now().duration_since(start).unwrap() >= Duration::from_millis(200));
+        assert_eq!(server.requests().len(), 2);
+        std::fs::remove_dir_all(client.state_dir()).unwrap();
+    }
+}
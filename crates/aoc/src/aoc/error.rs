@@ -0,0 +1,100 @@
+use std::fmt;
+
+/// Why a day could not produce an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AocError {
+    /// `line` and `column` are 1-based; `text` is the part of the input that
+    /// could not be parsed.
+    Parse { line: usize, column: usize, text: String, expected: String },
+    /// The input parsed, but does not lead to an answer.
+    NoSolution(String),
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, text: impl Into<String>, expected: impl Into<String>) -> Self {
+        AocError::Parse { line, column, text: text.into(), expected: expected.into() }
+    }
+
+    /// A parse error for `text`, which must be a slice of `input`. The line
+    /// and column are worked out from where `text` sits inside `input`.
+    pub fn parse_at(input: &str, text: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = locate(input, text);
+        Self::parse(line, column, text, expected)
+    }
+
+    /// Moves a parse error located within `part` to where it is in `input`,
+    /// for errors from parsing a slice of the input on its own.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        match self {
+            AocError::Parse { line, column, text, expected } => {
+                let (part_line, part_column) = locate(input, part);
+                let column = if line == 1 { part_column + column - 1 } else { column };
+                AocError::Parse { line: part_line + line - 1, column, text, expected }
+            }
+            other => other,
+        }
+    }
+}
+
+/// 1-based line and column (in chars) of `text` within `input`, or the start
+/// of `input` if `text` does not point into it.
+fn locate(input: &str, text: &str) -> (usize, usize) {
+    let offset = (text.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    if offset > input.len() || !input.is_char_boundary(offset) {
+        return (1, 1);
+    }
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse { line, column, text, expected } if text.is_empty() => {
+                write!(f, "line {line}, column {column}: expected {expected}, found nothing")
+            }
+            AocError::Parse { line, column, text, expected } => {
+                write!(f, "line {line}, column {column}: expected {expected}, found '{text}'")
+            }
+            AocError::NoSolution(reason) => write!(f, "no solution: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_at() {
+        let input = "1 2\n3 x4\n";
+        let error = AocError::parse_at(input, &input[6..8], "a number");
+        assert_eq!(error, AocError::parse(2, 3, "x4", "a number"));
+        assert_eq!(error.to_string(), "line 2, column 3: expected a number, found 'x4'");
+    }
+
+    #[test]
+    fn test_parse_at_foreign_text() {
+        let error = AocError::parse_at("1 2\n", "elsewhere", "a number");
+        assert_eq!(error, AocError::parse(1, 1, "elsewhere", "a number"));
+    }
+
+    #[test]
+    fn test_within() {
+        let input = "1 2\n3 x4 5\n6 y";
+        let part = &input[6..];
+        let error = AocError::parse_at(part, &part[0..2], "a number").within(input, part);
+        assert_eq!(error, AocError::parse(2, 3, "x4", "a number"));
+        let error = AocError::parse_at(part, &part[7..8], "a number").within(input, part);
+        assert_eq!(error, AocError::parse(3, 3, "y", "a number"));
+    }
+
+    #[test]
+    fn test_parse_at_end_of_line() {
+        let input = "12\n34";
+        assert_eq!(AocError::parse_at(input, &input[2..2], "a digit").to_string(), "line 1, column 3: expected a digit, found nothing");
+    }
+}
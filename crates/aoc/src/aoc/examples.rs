@@ -0,0 +1,138 @@
+use super::{Answer, Part, Solution};
+
+/// A worked example from a puzzle description, with the answer it should give
+/// for one part.
+pub struct Example {
+    pub file: &'static str,
+    pub input: &'static str,
+    pub part: Part,
+    pub expected: Answer,
+}
+
+/// Runs every example for `part` and panics listing all the ones that gave
+/// the wrong answer, rather than stopping at the first. Having no examples for
+/// `part` at all is a failure too, so a test cannot pass without checking.
+pub fn check(solution: &dyn Solution, part: Part, examples: &[Example]) {
+    let examples: Vec<&Example> = examples.iter().filter(|example| example.part == part).collect();
+    assert!(!examples.is_empty(), "day {} {part} has no examples", solution.day());
+    let failures: Vec<String> = examples
+        .into_iter()
+        .filter_map(|example| match solution.solve(part, example.input) {
+            Ok(answer) if answer == example.expected => None,
+            Ok(answer) => Some(format!("{}: expected {}, got {answer}", example.file, example.expected)),
+            Err(e) => Some(format!("{}: expected {}, got error: {e}", example.file, example.expected)),
+        })
+        .collect();
+    assert!(failures.is_empty(), "day {} {part} failed:\n  {}", solution.day(), failures.join("\n  "));
+}
+
+/// Generates a test for each part that has examples, running all of them.
+/// Each example file sits next to the calling file and lists the answers it is
+/// known to give:
+///
+/// ```ignore
+/// advent_of_code_2024::examples! {
+///     Day3,
+///     "example.txt" => { part_1: 161 },
+///     "example_2.txt" => { part_2: 48 },
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($solution:expr, $($file:literal => { $($part:ident: $expected:expr),+ $(,)? }),+ $(,)?) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+            use $crate::aoc::Part;
+            use $crate::aoc::examples::{check, Example};
+
+            fn examples() -> Vec<Example> {
+                vec![$($(
+                    Example {
+                        file: $file,
+                        input: include_str!($file),
+                        part: $crate::examples!(@part $part),
+                        expected: $expected.into(),
+                    },
+                )+)+]
+            }
+
+            $crate::examples!(@tests $solution, no no; $($($part)+)+);
+        }
+    };
+    (@part part_1) => { $crate::aoc::Part::One };
+    (@part part_2) => { $crate::aoc::Part::Two };
+
+    // works out which parts are listed at all, then writes their tests
+    (@tests $solution:expr, $one:ident $two:ident; part_1 $($rest:ident)*) => {
+        $crate::examples!(@tests $solution, yes $two; $($rest)*);
+    };
+    (@tests $solution:expr, $one:ident $two:ident; part_2 $($rest:ident)*) => {
+        $crate::examples!(@tests $solution, $one yes; $($rest)*);
+    };
+    (@tests $solution:expr, $one:ident $two:ident;) => {
+        $crate::examples!(@test $solution, $one part_1 One);
+        $crate::examples!(@test $solution, $two part_2 Two);
+    };
+    (@test $solution:expr, yes $name:ident $part:ident) => {
+        #[test]
+        fn $name() {
+            check(&$solution, Part::$part, &examples());
+        }
+    };
+    (@test $solution:expr, no $name:ident $part:ident) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::AocError;
+    use std::path::Path;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        fn day(&self) -> u32 {
+            1
+        }
+
+        fn title(&self) -> &'static str {
+            "Lengths"
+        }
+
+        fn input_path(&self) -> &'static Path {
+            Path::new("/nonexistent")
+        }
+
+        fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+            Ok(input.len().into())
+        }
+
+        fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+            Ok(input.lines().count().into())
+        }
+    }
+
+    fn example(input: &'static str, part: Part, expected: usize) -> Example {
+        Example { file: "example.txt", input, part, expected: expected.into() }
+    }
+
+    #[test]
+    fn test_check() {
+        let examples = [example("ab\ncd", Part::One, 5), example("ab\ncd", Part::Two, 2), example("a", Part::Two, 1)];
+        check(&Lengths, Part::One, &examples);
+        check(&Lengths, Part::Two, &examples);
+    }
+
+    #[test]
+    #[should_panic(expected = "day 1 part_2 has no examples")]
+    fn test_check_without_examples() {
+        check(&Lengths, Part::Two, &[example("ab\ncd", Part::One, 5)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "day 1 part_2 failed:\n  example.txt: expected 3, got 2")]
+    fn test_check_failure() {
+        check(&Lengths, Part::Two, &[example("ab\ncd", Part::One, 4), example("ab\ncd", Part::Two, 3)]);
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::visualize::{Frame, Palette, Rgb};
+
+/// How frames are drawn as images.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageOptions {
+    /// Width and height of one cell in pixels.
+    pub cell_size: u32,
+    /// Color of empty space, including the padding of rows shorter than the
+    /// longest.
+    pub background: Rgb,
+    /// Color of cells the palette has no color for.
+    pub foreground: Rgb,
+    /// How long each frame of a GIF is shown.
+    pub delay: Duration,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        Self {
+            cell_size: 8,
+            background: Rgb(20, 20, 20),
+            foreground: Rgb::WHITE,
+            delay: Duration::from_millis(100),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ExportError {
+    Io(io::Error),
+    Png(png::EncodingError),
+    Gif(gif::EncodingError),
+    TooLarge { width: u64, height: u64 },
+    TooManyColors(usize),
+}
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::Io(e) => write!(f, "{e}"),
+            ExportError::Png(e) => write!(f, "failed to encode PNG: {e}"),
+            ExportError::Gif(e) => write!(f, "failed to encode GIF: {e}"),
+            ExportError::TooLarge { width, height } => {
+                write!(f, "a {width}x{height} image is too large, try a smaller --cell size")
+            }
+            ExportError::TooManyColors(count) => write!(f, "a GIF holds at most 256 colors, these frames need {count}"),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExportError::Io(e) => Some(e),
+            ExportError::Png(e) => Some(e),
+            ExportError::Gif(e) => Some(e),
+            ExportError::TooLarge { .. } | ExportError::TooManyColors(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ExportError {
+    fn from(e: io::Error) -> Self {
+        ExportError::Io(e)
+    }
+}
+
+impl From<png::EncodingError> for ExportError {
+    fn from(e: png::EncodingError) -> Self {
+        ExportError::Png(e)
+    }
+}
+
+impl From<gif::EncodingError> for ExportError {
+    fn from(e: gif::EncodingError) -> Self {
+        ExportError::Gif(e)
+    }
+}
+
+/// Image size in pixels for `width` by `height` cells, bounded by the 16 bit
+/// sizes GIFs allow.
+fn image_size(width: usize, height: usize, options: &ImageOptions) -> Result<(u16, u16), ExportError> {
+    let scale = |cells: usize| cells as u64 * options.cell_size as u64;
+    let (width, height) = (scale(width).max(1), scale(height).max(1));
+    match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(w), Ok(h)) => Ok((w, h)),
+        _ => Err(ExportError::TooLarge { width, height }),
+    }
+}
+
+/// Draws `frame` onto a `width` by `height` pixel canvas, one `T` per pixel,
+/// with cells mapped through `pixel` and the rest left as `background`.
+fn rasterize<T: Copy>(frame: &Frame, size: (u16, u16), cell_size: u32, background: T, pixel: impl Fn(char) -> T) -> Vec<T> {
+    let (width, height) = (size.0 as usize, size.1 as usize);
+    let cell_size = cell_size as usize;
+    let mut canvas = vec![background; width * height];
+    for (y, row) in frame.rows().enumerate() {
+        for (x, &cell) in row.iter().enumerate() {
+            let value = pixel(cell);
+            for py in y * cell_size..((y + 1) * cell_size).min(height) {
+                canvas[py * width + x * cell_size..py * width + ((x + 1) * cell_size).min(width)].fill(value);
+            }
+        }
+    }
+    canvas
+}
+
+fn cell_color(cell: char, palette: &Palette, options: &ImageOptions) -> Rgb {
+    match (palette.color(cell), cell) {
+        (Some(color), _) => color,
+        (None, ' ') => options.background,
+        (None, _) => options.foreground,
+    }
+}
+
+pub fn write_png(frame: &Frame, palette: &Palette, options: &ImageOptions, out: impl Write) -> Result<(), ExportError> {
+    let size = image_size(frame.width(), frame.height(), options)?;
+    let pixels = rasterize(frame, size, options.cell_size, options.background, |cell| {
+        cell_color(cell, palette, options)
+    });
+
+    let mut encoder = png::Encoder::new(out, size.0 as u32, size.1 as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    let data: Vec<u8> = pixels.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect();
+    writer.write_image_data(&data)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Writes one `frame_NNNN.png` per frame into `dir`, creating it if needed,
+/// and returns the files written.
+pub fn write_png_sequence(frames: &[Frame], palette: &Palette, options: &ImageOptions, dir: &Path) -> Result<Vec<PathBuf>, ExportError> {
+    std::fs::create_dir_all(dir)?;
+    let digits = frames.len().to_string().len().max(4);
+    let mut paths = Vec::with_capacity(frames.len());
+    for (i, frame) in frames.iter().enumerate() {
+        let path = dir.join(format!("frame_{i:0digits$}.png"));
+        let mut out = BufWriter::new(File::create(&path)?);
+        write_png(frame, palette, options, &mut out)?;
+        out.flush()?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+/// Writes all frames as one looping GIF, sized to fit the largest frame.
+/// Every color comes from the palette, so frames share one color table and
+/// nothing is dithered.
+pub fn write_gif(frames: &[Frame], palette: &Palette, options: &ImageOptions, out: impl Write) -> Result<(), ExportError> {
+    let width = frames.iter().map(Frame::width).max().unwrap_or(0);
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0);
+    let size = image_size(width, height, options)?;
+
+    // index 0 is the background
+    let mut colors = vec![options.background];
+    let mut indices: HashMap<char, u8> = HashMap::new();
+    for cell in frames.iter().flat_map(|frame| frame.rows().flatten()) {
+        if indices.contains_key(cell) {
+            continue;
+        }
+        let color = cell_color(*cell, palette, options);
+        let index = match colors.iter().position(|&c| c == color) {
+            Some(index) => index,
+            None => {
+                colors.push(color);
+                colors.len() - 1
+            }
+        };
+        let index = u8::try_from(index).map_err(|_| ExportError::TooManyColors(colors.len()))?;
+        indices.insert(*cell, index);
+    }
+    let table: Vec<u8> = colors.iter().flat_map(|&Rgb(r, g, b)| [r, g, b]).collect();
+
+    let mut encoder = gif::Encoder::new(out, size.0, size.1, &table)?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    let delay = (options.delay.as_millis() / 10).clamp(1, u16::MAX as u128) as u16;
+    for frame in frames {
+        let pixels = rasterize(frame, size, options.cell_size, 0, |cell| indices[&cell]);
+        let mut frame = gif::Frame::from_indexed_pixels(size.0, size.1, pixels, None);
+        frame.delay = delay;
+        encoder.write_frame(&frame)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frames() -> Vec<Frame> {
+        vec![
+            Frame::from_rows(&[vec!['#', '.'], vec!['.', '^']]),
+            Frame::from_rows(&[vec!['#', '.', '.'], vec!['^']]),
+        ]
+    }
+
+    fn options() -> ImageOptions {
+        ImageOptions { cell_size: 2, ..ImageOptions::default() }
+    }
+
+    #[test]
+    fn test_rasterize() {
+        let frame = Frame::from_rows(&[vec!['a', 'b'], vec!['c']]);
+        let canvas = rasterize(&frame, (5, 4), 2, '-', |cell| cell);
+        let rows: Vec<String> = canvas.chunks(5).map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["aabb-", "aabb-", "cc  -", "cc  -"]);
+    }
+
+    #[test]
+    fn test_write_png() {
+        let palette = Palette::new().with("#", Rgb::GRAY);
+        let mut out = vec![];
+        write_png(&frames()[0], &palette, &options(), &mut out).unwrap();
+
+        let mut reader = png::Decoder::new(io::Cursor::new(out)).read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut data).unwrap();
+        assert_eq!((info.width, info.height), (4, 4));
+        assert_eq!(data[..3], [110, 110, 110]);
+        assert_eq!(data[data.len() - 3..], [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_write_gif() {
+        let palette = Palette::new().with("#", Rgb::GRAY).with(".", Rgb::BLACK);
+        let mut out = vec![];
+        write_gif(&frames(), &palette, &options(), &mut out).unwrap();
+
+        let mut decoder = gif::DecodeOptions::new();
+        decoder.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = decoder.read_info(io::Cursor::new(out)).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (6, 4));
+        assert_eq!(decoder.global_palette().unwrap().len(), 4 * 3);
+        let mut delays = vec![];
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            delays.push(frame.delay);
+        }
+        assert_eq!(delays, [10, 10]);
+
+        let huge = Frame::wrapped(std::iter::repeat_n('#', 40_000), 40_000);
+        assert!(matches!(write_gif(&[huge], &palette, &options(), io::sink()), Err(ExportError::TooLarge { .. })));
+    }
+}
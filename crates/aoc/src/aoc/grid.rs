@@ -0,0 +1,269 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use super::AocError;
+use super::point::{Direction, Point, Vec2};
+
+/// Steps to the cells above, right of, below and left of a cell.
+pub const NEIGHBORS_4: [Vec2; 4] = [Direction::Up.vec(), Direction::Right.vec(), Direction::Down.vec(), Direction::Left.vec()];
+
+/// `NEIGHBORS_4` plus the diagonals, clockwise from above.
+pub const NEIGHBORS_8: [Vec2; 8] = [
+    Vec2::new(0, -1),
+    Vec2::new(1, -1),
+    Vec2::new(1, 0),
+    Vec2::new(1, 1),
+    Vec2::new(0, 1),
+    Vec2::new(-1, 1),
+    Vec2::new(-1, 0),
+    Vec2::new(-1, -1),
+];
+
+/// A rectangular grid of cells, addressed as `(x, y)` with `(0, 0)` at the
+/// top left. Out of bounds lookups are checked rather than padded for.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Parses one cell per character, one row per line. `cell` returns
+    /// `None` for characters it does not accept, which are reported as not
+    /// being `expected`. Every row must be as wide as the first.
+    pub fn parse(input: &str, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, AocError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| AocError::parse_at(input, &line[i..i + c.len_utf8()], expected))?;
+                cells.push(value);
+            }
+            let row_width = cells.len() - row_start;
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                // point at whatever is missing or left over
+                let end = line.char_indices().nth(width).map_or(line.len(), |(i, _)| i);
+                return Err(AocError::parse_at(input, &line[end..], format!("{width} cells in every row")));
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Self { width, height, cells }),
+            _ => Err(AocError::parse(1, 1, "", "a grid of at least one cell")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    /// Numbers the cells `0..width * height`, row by row, for structures
+    /// indexed by number such as `UnionFind`.
+    pub fn index_of(&self, (x, y): (usize, usize)) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    /// The position of cell number `index`; the reverse of `index_of`.
+    pub fn position_of(&self, index: usize) -> Option<(usize, usize)> {
+        (index < self.cells.len()).then(|| (index % self.width, index / self.width))
+    }
+
+    /// The position of `point`, for points worked out with arithmetic that
+    /// may leave the grid.
+    pub fn checked_position(&self, point: Point) -> Option<(usize, usize)> {
+        let (x, y) = point.to_index()?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    /// One `step` from `position`, if that stays on the grid.
+    pub fn offset(&self, position: (usize, usize), step: impl Into<Vec2>) -> Option<(usize, usize)> {
+        step_within(self.width, self.height, position, step.into())
+    }
+
+    /// The up to four cells sharing an edge with `position`, clockwise from
+    /// above. Borrows nothing, so the grid can be changed while iterating.
+    pub fn neighbors(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        NEIGHBORS_4.into_iter().filter_map(move |step| step_within(width, height, position, step))
+    }
+
+    /// Like `neighbors`, including the diagonals.
+    pub fn neighbors_8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let (width, height) = (self.width, self.height);
+        NEIGHBORS_8.into_iter().filter_map(move |step| step_within(width, height, position, step))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, row by row, whose cell matches.
+    pub fn find(&self, mut matches: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter().find(|(_, cell)| matches(cell)).map(|(position, _)| position)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+// `offset` without borrowing the grid
+fn step_within(width: usize, height: usize, (x, y): (usize, usize), step: Vec2) -> Option<(usize, usize)> {
+    let (x, y) = (x.checked_add_signed(step.x)?, y.checked_add_signed(step.y)?);
+    (x < width && y < height).then_some((x, y))
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.index_of((x, y)) {
+            Some(i) => &self.cells[i],
+            None => panic!("({x}, {y}) is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.index_of((x, y)) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("({x}, {y}) is outside the {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+/// A grid of the input's characters as they are.
+impl FromStr for Grid<char> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, "any character", Some)
+    }
+}
+
+/// Rows on separate lines, with no newline after the last.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, AocError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        assert_eq!(digits("123\n4x6").unwrap_err(), AocError::parse(2, 2, "x", "a digit"));
+        assert_eq!(digits("123\n45\n").unwrap_err(), AocError::parse(2, 3, "", "3 cells in every row"));
+        assert_eq!(digits("12\n345").unwrap_err(), AocError::parse(2, 3, "5", "2 cells in every row"));
+        assert_eq!(digits("").unwrap_err(), AocError::parse(1, 1, "", "a grid of at least one cell"));
+        assert_eq!("#.\n.#".parse::<Grid<char>>().unwrap().find(|&c| c == '#'), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = digits("12\n34").unwrap();
+        assert_eq!(grid.get((1, 0)), Some(&2));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        *grid.get_mut((0, 1)).unwrap() = 9;
+        grid[(1, 1)] += 1;
+        assert_eq!(grid.to_string(), "12\n95");
+
+        assert_eq!(grid.checked_position(Point::new(1, 1)), Some((1, 1)));
+        assert_eq!(grid.checked_position(Point::new(-1, 0)), None);
+        assert_eq!(grid.checked_position(Point::new(0, 2)), None);
+        assert_eq!(grid.offset((0, 0), (1, 1)), Some((1, 1)));
+        assert_eq!(grid.offset((0, 0), Direction::Up), None);
+        assert_eq!(grid.offset((0, 0), Direction::Down), Some((0, 1)));
+        assert_eq!(grid.find(|&cell| cell > 4), Some((0, 1)));
+        assert_eq!(grid.map(|&cell| cell % 2 == 0).get((1, 0)), Some(&true));
+        assert_eq!(grid.index_of((1, 1)), Some(3));
+        assert_eq!(grid.index_of((2, 0)), None);
+        assert_eq!(grid.position_of(2), Some((0, 1)));
+        assert_eq!(grid.position_of(4), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, '.');
+        assert_eq!(grid.neighbors((1, 1)).collect::<Vec<_>>(), [(1, 0), (2, 1), (1, 2), (0, 1)]);
+        assert_eq!(grid.neighbors((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors_8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors_8((2, 2)).collect::<Vec<_>>(), [(2, 1), (1, 2), (1, 1)]);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), [&2, &5]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.columns().map(|column| column.sum::<u32>()).collect::<Vec<_>>(), [5, 7, 9]);
+        assert_eq!(grid.positions().collect::<Vec<_>>(), [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        assert_eq!(grid.iter().filter(|&(_, &cell)| cell % 2 == 0).map(|(position, _)| position).collect::<Vec<_>>(), [(1, 0), (0, 1), (2, 1)]);
+    }
+}
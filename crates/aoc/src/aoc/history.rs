@@ -0,0 +1,173 @@
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use super::bench::Stats;
+
+/// One benchmarked step of a day, such as `parse` or `part_1`, at a revision.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub step: String,
+    pub revision: String,
+    pub stats: Stats,
+    /// Seconds since the unix epoch.
+    pub at: u64,
+}
+
+/// How a step's median compares with its baseline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Comparison {
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change in percent; positive means slower.
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_nanos().max(1) as f64;
+        (self.current.as_nanos() as f64 - baseline) / baseline * 100.0
+    }
+
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.change() > threshold_percent
+    }
+}
+
+/// Past benchmark results, one tab-separated line per day, step and revision:
+/// `day step revision iterations min median p95 mean timestamp`, with times
+/// in nanoseconds. Benchmarking the same step at the same revision again
+/// replaces the old line.
+pub struct History {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl History {
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut records = vec![];
+        for (i, line) in contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let record = parse_record(line).ok_or_else(|| io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}:{}: malformed history entry '{line}'", path.display(), i + 1),
+            ))?;
+            records.push(record);
+        }
+        Ok(Self { path, records })
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// The record to compare against: the one at `revision` if given,
+    /// otherwise the latest one from any revision other than `current`.
+    pub fn baseline(&self, day: u32, step: &str, current: &str, revision: Option<&str>) -> Option<&Record> {
+        self.records
+            .iter()
+            .filter(|record| record.day == day && record.step == step)
+            .filter(|record| match revision {
+                Some(revision) => record.revision == revision,
+                None => record.revision != current,
+            })
+            .max_by_key(|record| record.at)
+    }
+
+    pub fn record(&mut self, record: Record) -> io::Result<()> {
+        self.records.retain(|r| (r.day, &r.step, &r.revision) != (record.day, &record.step, &record.revision));
+        self.records.push(record);
+
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = io::BufWriter::new(std::fs::File::create(&self.path)?);
+        for record in &self.records {
+            let Stats { iterations, min, median, p95, mean } = record.stats;
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{iterations}\t{}\t{}\t{}\t{}\t{}",
+                record.day,
+                record.step,
+                record.revision,
+                min.as_nanos(),
+                median.as_nanos(),
+                p95.as_nanos(),
+                mean.as_nanos(),
+                record.at,
+            )?;
+        }
+        file.flush()
+    }
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let mut fields = line.split('\t');
+    let day = fields.next()?.parse().ok()?;
+    let step = fields.next()?.to_string();
+    let revision = fields.next()?.to_string();
+    let iterations = fields.next()?.parse().ok()?;
+    let mut nanos = || fields.next()?.parse().ok().map(Duration::from_nanos);
+    let stats = Stats { iterations, min: nanos()?, median: nanos()?, p95: nanos()?, mean: nanos()? };
+    let at = fields.next()?.parse().ok()?;
+    fields.next().is_none().then_some(Record { day, step, revision, stats, at })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(step: &str, revision: &str, median_ms: u64, at: u64) -> Record {
+        let median = Duration::from_millis(median_ms);
+        Record {
+            day: 6,
+            step: step.to_string(),
+            revision: revision.to_string(),
+            stats: Stats { iterations: 10, min: median, median, p95: median, mean: median },
+            at,
+        }
+    }
+
+    #[test]
+    fn test_comparison() {
+        let comparison = Comparison { baseline: Duration::from_millis(100), current: Duration::from_millis(115) };
+        assert!((comparison.change() - 15.0).abs() < 1e-9);
+        assert!(comparison.is_regression(10.0));
+        assert!(!comparison.is_regression(20.0));
+    }
+
+    #[test]
+    fn test_history() {
+        let path = std::env::temp_dir().join(format!("aoc_history_test_{}.tsv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut history = History::load(&path).unwrap();
+        history.record(record("part_2", "aaa", 100, 1)).unwrap();
+        history.record(record("part_2", "bbb", 120, 2)).unwrap();
+        history.record(record("part_2", "bbb", 130, 3)).unwrap();
+        history.record(record("part_1", "bbb", 5, 3)).unwrap();
+
+        let history = History::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(history.records().len(), 3);
+        assert_eq!(history.baseline(6, "part_2", "ccc", None), Some(&record("part_2", "bbb", 130, 3)));
+        assert_eq!(history.baseline(6, "part_2", "bbb", None), Some(&record("part_2", "aaa", 100, 1)));
+        assert_eq!(history.baseline(6, "part_2", "ccc", Some("aaa")), Some(&record("part_2", "aaa", 100, 1)));
+        assert_eq!(history.baseline(6, "part_1", "bbb", None), None);
+        assert_eq!(history.baseline(7, "part_2", "ccc", None), None);
+    }
+
+    #[test]
+    fn test_malformed_history() {
+        assert_eq!(parse_record("6\tpart_1\taaa\t10\t1\t2\t3\t4\t5").map(|r| r.stats.p95), Some(Duration::from_nanos(3)));
+        assert_eq!(parse_record("6\tpart_1\taaa\t10\t1\t2\t3\t4"), None);
+        assert_eq!(parse_record("6\tpart_1\taaa\t10\t1\t2\t3\t4\t5\t6"), None);
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use super::Solution;
+
+/// Directory holding `day_N.txt` files, used instead of the bundled inputs
+/// when set.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    /// A directory containing one `day_N.txt` file per day.
+    Dir(PathBuf),
+    /// `$AOC_INPUT_DIR` if it is set, otherwise the file bundled with the day.
+    #[default]
+    Default,
+}
+
+impl InputSource {
+    /// Interprets a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { day: u32, path: PathBuf },
+    Read { origin: String, source: io::Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { day, path } => write!(
+                f,
+                "no input for day {day}: {} does not exist (pass an input file, or set {INPUT_DIR_VAR} to a directory containing day_{day}.txt)",
+                path.display(),
+            ),
+            InputError::Read { origin, source } => write!(f, "failed to read {origin}: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Read { source, .. } => Some(source),
+        }
+    }
+}
+
+pub fn load(solution: &dyn Solution, source: &InputSource) -> Result<String, InputError> {
+    match path(solution, source) {
+        Some(path) => read_file(solution.day(), &path),
+        None => read_stdin(),
+    }
+}
+
+/// The file `load` reads for `source`, or `None` for stdin.
+pub fn path(solution: &dyn Solution, source: &InputSource) -> Option<PathBuf> {
+    resolve(solution, source, std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from))
+}
+
+/// Picks the file to read for `source`, or `None` for stdin.
+fn resolve(solution: &dyn Solution, source: &InputSource, env_dir: Option<PathBuf>) -> Option<PathBuf> {
+    let dir_file = |dir: &Path| dir.join(format!("day_{}.txt", solution.day()));
+    match source {
+        InputSource::File(path) => Some(path.clone()),
+        InputSource::Stdin => None,
+        InputSource::Dir(dir) => Some(dir_file(dir)),
+        InputSource::Default => match env_dir {
+            Some(dir) => Some(dir_file(&dir)),
+            None => Some(solution.input_path().to_path_buf()),
+        },
+    }
+}
+
+fn read_file(day: u32, path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing { day, path: path.to_path_buf() },
+        _ => InputError::Read { origin: path.display().to_string(), source },
+    })
+}
+
+fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|source| InputError::Read { origin: "stdin".to_string(), source })?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::{AocError, Answer};
+
+    struct Fake;
+
+    impl Solution for Fake {
+        fn day(&self) -> u32 {
+            7
+        }
+
+        fn title(&self) -> &'static str {
+            "Fake"
+        }
+
+        fn input_path(&self) -> &'static Path {
+            Path::new("/nonexistent/day_7/src/input.txt")
+        }
+
+        fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+            Ok(input.len().into())
+        }
+
+        fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+            Ok(input.lines().count().into())
+        }
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(resolve(&Fake, &InputSource::from_arg("-"), None), None);
+        assert_eq!(
+            resolve(&Fake, &InputSource::from_arg("a/b.txt"), Some(PathBuf::from("dir"))),
+            Some(PathBuf::from("a/b.txt")),
+        );
+        assert_eq!(
+            resolve(&Fake, &InputSource::Dir(PathBuf::from("inputs")), None),
+            Some(PathBuf::from("inputs/day_7.txt")),
+        );
+        assert_eq!(
+            resolve(&Fake, &InputSource::Default, Some(PathBuf::from("env"))),
+            Some(PathBuf::from("env/day_7.txt")),
+        );
+        assert_eq!(
+            resolve(&Fake, &InputSource::Default, None),
+            Some(PathBuf::from("/nonexistent/day_7/src/input.txt")),
+        );
+    }
+
+    #[test]
+    fn test_load_from_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc_input_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("day_7.txt"), "1 2 3\n").unwrap();
+
+        let input = load(&Fake, &InputSource::Dir(dir.clone()));
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(input.unwrap(), "1 2 3\n");
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let error = load(&Fake, &InputSource::File(Fake.input_path().to_path_buf())).unwrap_err();
+        assert!(matches!(error, InputError::Missing { day: 7, .. }));
+        assert!(error.to_string().contains("/nonexistent/day_7/src/input.txt"));
+    }
+}
@@ -0,0 +1,154 @@
+use std::fmt;
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Names the level to log at, such as `AOC_LOG=debug`.
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// How much a message matters; each level includes the ones before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [Level::Error, Level::Warn, Level::Info, Level::Debug, Level::Trace];
+
+    /// The level for `-v`, `-vv` or `-vvv`.
+    pub fn from_verbosity(count: usize) -> Option<Self> {
+        match count {
+            0 => None,
+            1 => Some(Level::Info),
+            2 => Some(Level::Debug),
+            _ => Some(Level::Trace),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Level::ALL
+            .into_iter()
+            .find(|level| level.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown log level '{s}', expected error, warn, info, debug or trace"))
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+// 0 means logging is off, which it is until `init` says otherwise
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_level(level: Option<Level>) {
+    MAX_LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Logs at `level` if given, such as from `-v`, otherwise at the level named
+/// by `$AOC_LOG`. With neither, nothing is logged.
+pub fn init(level: Option<Level>) -> Result<(), String> {
+    let level = match (level, std::env::var(LOG_VAR)) {
+        (Some(level), _) => Some(level),
+        (None, Ok(name)) if !name.is_empty() => Some(name.parse().map_err(|e| format!("{LOG_VAR}: {e}"))?),
+        (None, _) => None,
+    };
+    set_level(level);
+    Ok(())
+}
+
+/// Writes a message to stderr; use the `error!` to `trace!` macros, which
+/// skip formatting when the level is off.
+pub fn write(level: Level, module: &str, args: fmt::Arguments) {
+    let _ = writeln!(std::io::stderr().lock(), "[{level:<5} {module}] {args}");
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::aoc::log::enabled($level) {
+            $crate::aoc::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::aoc::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::aoc::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::aoc::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::aoc::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::aoc::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!("debug".parse(), Ok(Level::Debug));
+        assert_eq!("TRACE".parse(), Ok(Level::Trace));
+        assert!("verbose".parse::<Level>().is_err());
+        assert_eq!(Level::from_verbosity(0), None);
+        assert_eq!(Level::from_verbosity(2), Some(Level::Debug));
+        assert_eq!(Level::from_verbosity(5), Some(Level::Trace));
+    }
+
+    #[test]
+    fn test_enabled() {
+        let mut formatted = 0;
+        let mut message = || {
+            formatted += 1;
+            "message"
+        };
+
+        set_level(None);
+        assert!(!enabled(Level::Error));
+        crate::error!("{}", message());
+        set_level(Some(Level::Info));
+        assert!(enabled(Level::Warn) && enabled(Level::Info) && !enabled(Level::Debug));
+        crate::debug!("{}", message());
+        set_level(None);
+        assert_eq!(formatted, 0);
+    }
+}
@@ -0,0 +1,139 @@
+//! Parsers for the shapes puzzle inputs keep coming in.
+//!
+//! Errors are located within the `text` each parser is given. Parsers that
+//! hand part of their text on to a closure move the closure's errors to the
+//! right place themselves; for anything else, such as a section parsed on its
+//! own, see `AocError::within`.
+
+use std::str::FromStr;
+
+use super::AocError;
+use super::grid::Grid;
+
+/// Parses the whole of `text` as one value.
+pub fn value<T: FromStr>(text: &str, expected: &str) -> Result<T, AocError> {
+    text.parse().map_err(|_| AocError::parse_at(text, text, expected))
+}
+
+/// Whitespace separated numbers, on any number of lines.
+pub fn ints<T: FromStr>(text: &str, expected: &str) -> Result<Vec<T>, AocError> {
+    text.split_whitespace().map(|item| item.parse().map_err(|_| AocError::parse_at(text, item, expected))).collect()
+}
+
+/// Values with `separator` between them, like `75,47,61`.
+pub fn separated<T: FromStr>(text: &str, separator: &str, expected: &str) -> Result<Vec<T>, AocError> {
+    text.split(separator).map(|item| item.parse().map_err(|_| AocError::parse_at(text, item, expected))).collect()
+}
+
+/// Parses each line with `line`.
+pub fn lines<T>(text: &str, mut line: impl FnMut(&str) -> Result<T, AocError>) -> Result<Vec<T>, AocError> {
+    text.lines().map(|part| line(part).map_err(|error| error.within(text, part))).collect()
+}
+
+/// One value per line.
+pub fn lines_of<T: FromStr>(text: &str, expected: &str) -> Result<Vec<T>, AocError> {
+    lines(text, |line| value(line, expected))
+}
+
+/// The blocks of lines between blank lines, without their line breaks.
+pub fn sections(text: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&text[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        sections.push(&text[start..end]);
+    }
+    sections
+}
+
+/// Lines of a key and a value split by `separator`, like `190: 10 19` or
+/// `47|53`. Lines without the separator are reported as not being `expected`.
+pub fn key_values<K, V>(
+    text: &str,
+    separator: &str,
+    expected: &str,
+    mut key: impl FnMut(&str) -> Result<K, AocError>,
+    mut value: impl FnMut(&str) -> Result<V, AocError>,
+) -> Result<Vec<(K, V)>, AocError> {
+    lines(text, |line| {
+        let Some((key_text, value_text)) = line.split_once(separator) else {
+            return Err(AocError::parse_at(line, line, expected));
+        };
+        let key = key(key_text).map_err(|error| error.within(line, key_text))?;
+        let value = value(value_text).map_err(|error| error.within(line, value_text))?;
+        Ok((key, value))
+    })
+}
+
+/// A grid of single digits.
+pub fn digit_grid(text: &str, expected: &str) -> Result<Grid<u32>, AocError> {
+    Grid::parse(text, expected, |c| c.to_digit(10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i32>("3   4\n-4 3\n", "a number"), Ok(vec![3, 4, -4, 3]));
+        assert_eq!(ints::<u8>("1 2\n3 300", "a byte"), Err(AocError::parse(2, 3, "300", "a byte")));
+        assert_eq!(separated::<i32>("75,47,61", ",", "a page"), Ok(vec![75, 47, 61]));
+        assert_eq!(separated::<i32>("75,,61", ",", "a page"), Err(AocError::parse(1, 4, "", "a page")));
+        assert_eq!(value::<u64>("12", "a number"), Ok(12));
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(lines_of::<u32>("1\n2\n", "a number"), Ok(vec![1, 2]));
+        assert_eq!(lines_of::<u32>("1\n2 3\n", "a number"), Err(AocError::parse(2, 1, "2 3", "a number")));
+        let error = lines("1 2\n3 x\n", |line| ints::<u32>(line, "a number")).unwrap_err();
+        assert_eq!(error, AocError::parse(2, 3, "x", "a number"));
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "47|53\n97|13\n\n75,47\n6x\n\n\n1\n";
+        assert_eq!(sections(input), ["47|53\n97|13", "75,47\n6x", "1"]);
+        assert_eq!(sections("a\r\n\r\nb"), ["a", "b"]);
+        assert!(sections("\n\n").is_empty());
+
+        // errors can be moved from a section onto the whole input
+        let second = sections(input)[1];
+        let error = lines(second, |line| separated::<u32>(line, ",", "a page")).unwrap_err();
+        assert_eq!(error, AocError::parse(2, 1, "6x", "a page"));
+        assert_eq!(error.within(input, second), AocError::parse(5, 1, "6x", "a page"));
+    }
+
+    #[test]
+    fn test_key_values() {
+        let number = |text: &str| value::<u64>(text, "a number");
+        let parsed = key_values("190: 10 19\n3267: 81", ": ", "an equation", number, |text| ints::<u64>(text, "a number"));
+        assert_eq!(parsed, Ok(vec![(190, vec![10, 19]), (3267, vec![81])]));
+
+        let error = key_values("1|2\n3-4", "|", "a rule", number, number).unwrap_err();
+        assert_eq!(error, AocError::parse(2, 1, "3-4", "a rule"));
+        let error = key_values("1|2\n3|x", "|", "a rule", number, number).unwrap_err();
+        assert_eq!(error, AocError::parse(2, 3, "x", "a number"));
+    }
+
+    #[test]
+    fn test_digit_grid() {
+        let grid = digit_grid("01\n23", "a digit").unwrap();
+        assert_eq!(grid[(1, 1)], 3);
+        assert_eq!(digit_grid("01\n2.", "a digit").unwrap_err(), AocError::parse(2, 2, ".", "a digit"));
+    }
+}
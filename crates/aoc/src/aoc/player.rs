@@ -0,0 +1,248 @@
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+use super::visualize::{Frame, Palette, Rgb};
+
+const CONTROLS: &str = "space pause  n/p step  +/- speed  r restart  q quit";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Key {
+    Pause,
+    Next,
+    Previous,
+    Faster,
+    Slower,
+    Restart,
+    Quit,
+}
+
+impl Key {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b' ' => Some(Key::Pause),
+            b'n' | b'.' => Some(Key::Next),
+            b'p' | b',' => Some(Key::Previous),
+            b'+' | b'=' => Some(Key::Faster),
+            b'-' | b'_' => Some(Key::Slower),
+            b'r' => Some(Key::Restart),
+            // ctrl-c arrives as a byte because signals are off while playing
+            b'q' | 3 => Some(Key::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Where playback is, apart from any terminal handling.
+#[derive(Debug, PartialEq)]
+struct Playback {
+    index: usize,
+    len: usize,
+    fps: f64,
+    paused: bool,
+}
+
+impl Playback {
+    fn new(len: usize, fps: f64) -> Self {
+        Self { index: 0, len, fps: fps.clamp(0.25, 240.0), paused: false }
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+
+    /// Moves on one frame, pausing on the last. Returns false if there was no
+    /// frame to move on to.
+    fn advance(&mut self) -> bool {
+        if self.index + 1 < self.len {
+            self.index += 1;
+            true
+        } else {
+            self.paused = true;
+            false
+        }
+    }
+
+    /// Applies a key press, returning false if it means stop.
+    fn press(&mut self, key: Key) -> bool {
+        match key {
+            Key::Pause => self.paused = !self.paused,
+            Key::Next => {
+                self.paused = true;
+                self.advance();
+            }
+            Key::Previous => {
+                self.paused = true;
+                self.index = self.index.saturating_sub(1);
+            }
+            Key::Faster => self.fps = (self.fps * 2.0).min(240.0),
+            Key::Slower => self.fps = (self.fps / 2.0).max(0.25),
+            Key::Restart => self.index = 0,
+            Key::Quit => return false,
+        }
+        true
+    }
+
+    fn status(&self) -> String {
+        let paused = if self.paused { "  [paused]" } else { "" };
+        format!("frame {}/{}  {} fps{paused}", self.index + 1, self.len, self.fps)
+    }
+}
+
+/// Draws a frame from the top left of the screen, with cells colored by
+/// `palette` and `status` underneath.
+fn render(frame: &Frame, palette: &Palette, status: &str) -> String {
+    let mut screen = String::from("\x1b[H");
+    if !frame.caption.is_empty() {
+        screen += &format!("{}\x1b[K\n", frame.caption);
+    }
+    for row in frame.rows() {
+        let mut current = None;
+        for &cell in row {
+            let color = palette.color(cell);
+            if color != current {
+                screen += &match color {
+                    Some(Rgb(r, g, b)) => format!("\x1b[38;2;{r};{g};{b}m"),
+                    None => "\x1b[39m".to_string(),
+                };
+                current = color;
+            }
+            screen.push(cell);
+        }
+        screen += "\x1b[0m\x1b[K\n";
+    }
+    screen + status + "\x1b[K\n\x1b[J"
+}
+
+/// Puts the terminal into unbuffered, silent input for reading single key
+/// presses, and puts it back when dropped. Uses `stty`, so it needs no more
+/// than a plain Linux terminal.
+struct RawTerminal {
+    saved: String,
+}
+
+impl RawTerminal {
+    fn enable() -> Option<Self> {
+        let stty = |args: &[&str]| {
+            let output = Command::new("stty").args(args).stdin(Stdio::inherit()).stderr(Stdio::null()).output().ok()?;
+            output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        print!("\x1b[?25l\x1b[2J");
+        Some(Self { saved })
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = Command::new("stty").arg(&self.saved).stdin(Stdio::inherit()).status();
+        print!("\x1b[?25h");
+        let _ = io::stdout().flush();
+    }
+}
+
+/// Replays `frames` at `fps` frames per second. In a terminal the keys in
+/// `CONTROLS` pause, step and change speed, and playback waits on the last
+/// frame until quit; otherwise the frames play through once.
+pub fn play(frames: &[Frame], palette: &Palette, fps: f64) -> io::Result<()> {
+    if frames.is_empty() {
+        return Ok(());
+    }
+    let terminal = match io::stdin().is_terminal() && io::stdout().is_terminal() {
+        true => RawTerminal::enable(),
+        false => None,
+    };
+    let (sender, keys) = mpsc::channel();
+    if terminal.is_some() {
+        std::thread::spawn(move || {
+            for byte in io::stdin().lock().bytes() {
+                let Ok(byte) = byte else {
+                    break;
+                };
+                if let Some(key) = Key::from_byte(byte)
+                    && sender.send(key).is_err()
+                {
+                    break;
+                }
+            }
+        });
+    } else {
+        drop(sender);
+    }
+
+    let mut playback = Playback::new(frames.len(), fps);
+    let mut out = io::stdout().lock();
+    loop {
+        let status = match terminal {
+            Some(_) => format!("{}  {CONTROLS}", playback.status()),
+            None => playback.status(),
+        };
+        out.write_all(render(&frames[playback.index], palette, &status).as_bytes())?;
+        out.flush()?;
+
+        let key = match (&terminal, playback.paused) {
+            (None, _) => {
+                std::thread::sleep(playback.interval());
+                None
+            }
+            (Some(_), true) => Some(keys.recv().unwrap_or(Key::Quit)),
+            (Some(_), false) => match keys.recv_timeout(playback.interval()) {
+                Ok(key) => Some(key),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => Some(Key::Quit),
+            },
+        };
+        match key {
+            Some(key) if !playback.press(key) => break,
+            Some(_) => {}
+            None if !playback.advance() && terminal.is_none() => break,
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_playback() {
+        let mut playback = Playback::new(3, 10.0);
+        assert!(playback.advance());
+        assert!(playback.advance());
+        assert!(!playback.advance());
+        assert!(playback.paused);
+        assert_eq!(playback.status(), "frame 3/3  10 fps  [paused]");
+
+        playback.press(Key::Restart);
+        playback.press(Key::Pause);
+        assert_eq!((playback.index, playback.paused), (0, false));
+        playback.press(Key::Next);
+        assert_eq!((playback.index, playback.paused), (1, true));
+        playback.press(Key::Previous);
+        playback.press(Key::Previous);
+        assert_eq!(playback.index, 0);
+        playback.press(Key::Faster);
+        assert_eq!(playback.interval(), Duration::from_millis(50));
+        assert!(!playback.press(Key::Quit));
+        assert_eq!(Key::from_byte(b'x'), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let frame = Frame::from_rows(&[vec!['#', '#', '.'], vec!['.', '^', '.']]).with_caption("step 1");
+        let palette = Palette::new().with("#", Rgb(1, 2, 3)).with("^", Rgb::RED);
+        assert_eq!(
+            render(&frame, &palette, "frame 1/1"),
+            concat!(
+                "\x1b[Hstep 1\x1b[K\n",
+                "\x1b[38;2;1;2;3m##\x1b[39m.\x1b[0m\x1b[K\n",
+                ".\x1b[38;2;220;50;47m^\x1b[39m.\x1b[0m\x1b[K\n",
+                "frame 1/1\x1b[K\n\x1b[J",
+            ),
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on the plane, `x` to the right and `y` downwards so that it
+/// lines up with grid rows. Signed, so arithmetic may leave a grid; see
+/// `Grid::checked_position` for getting back onto one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// The displacement between two points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// One of the four steps between cells that share an edge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        (self - other).manhattan()
+    }
+
+    /// The grid index `(x, y)` of this point, unless it is left of or above
+    /// the grid. Whether it fits on the right or below is up to the grid.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// A quarter turn clockwise, as seen with `y` pointing down.
+    pub fn turn_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.reverse().turn_right()
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub const fn vec(self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(-1, 0),
+        }
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x as isize, y as isize)
+    }
+}
+
+impl From<(isize, isize)> for Vec2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Self {
+        direction.vec()
+    }
+}
+
+impl<V: Into<Vec2>> Add<V> for Point {
+    type Output = Point;
+
+    fn add(self, step: V) -> Point {
+        let step = step.into();
+        Point::new(self.x + step.x, self.y + step.y)
+    }
+}
+
+impl<V: Into<Vec2>> Sub<V> for Point {
+    type Output = Point;
+
+    fn sub(self, step: V) -> Point {
+        self + -step.into()
+    }
+}
+
+impl<V: Into<Vec2>> AddAssign<V> for Point {
+    fn add_assign(&mut self, step: V) {
+        *self = *self + step;
+    }
+}
+
+impl<V: Into<Vec2>> SubAssign<V> for Point {
+    fn sub_assign(&mut self, step: V) {
+        *self = *self - step;
+    }
+}
+
+/// `Point - Point`, since `Point` is not `Into<Vec2>`.
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, other: Point) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        self + -other
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: isize) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(3, 1);
+        let b = Point::new(1, 4);
+        assert_eq!(a - b, Vec2::new(2, -3));
+        assert_eq!(b + (a - b), a);
+        assert_eq!(a + (a - b) * 2, Point::new(7, -5));
+        assert_eq!(a - Direction::Up, Point::new(3, 2));
+        assert_eq!(a.manhattan(b), 5);
+
+        let mut c = a;
+        c += Direction::Left;
+        c -= Vec2::new(0, 1);
+        assert_eq!(c, Point::new(2, 0));
+        assert_eq!(c.to_string(), "(2, 0)");
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+        for direction in Direction::ALL {
+            assert_eq!(direction.vec().turn_right(), direction.turn_right().vec());
+            assert_eq!(direction.vec().turn_left(), direction.turn_left().vec());
+            assert_eq!(-direction.vec(), direction.reverse().vec());
+        }
+    }
+
+    #[test]
+    fn test_index() {
+        assert_eq!(Point::from((2, 5)), Point::new(2, 5));
+        assert_eq!(Point::new(2, 5).to_index(), Some((2, 5)));
+        assert_eq!(Point::new(-1, 5).to_index(), None);
+        assert_eq!(Point::new(0, -1).to_index(), None);
+    }
+}
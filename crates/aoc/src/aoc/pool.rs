@@ -0,0 +1,62 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// The number of threads to use when none is asked for.
+pub fn default_jobs() -> usize {
+    std::thread::available_parallelism().map_or(1, usize::from)
+}
+
+/// Calls `f` on every item from `jobs` worker threads and returns the results
+/// in item order. Workers pick up the next unclaimed item as soon as they are
+/// free, so one slow item does not hold back the rest.
+pub fn map<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(items.len()));
+
+    std::thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else {
+                        break;
+                    };
+                    let result = f(item);
+                    results.lock().unwrap().push((i, result));
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items: Vec<u64> = (0..20).collect();
+        // later items finish first
+        let squares = map(&items, 4, |&i| {
+            std::thread::sleep(Duration::from_millis(20 - i));
+            i * i
+        });
+        assert_eq!(squares, items.iter().map(|i| i * i).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_map_uses_threads() {
+        let threads = map(&[(); 8], 4, |_| {
+            std::thread::sleep(Duration::from_millis(10));
+            std::thread::current().id()
+        });
+        assert!(threads.iter().collect::<HashSet<_>>().len() > 1);
+        assert!(map(&[] as &[u32], 4, |&i| i).is_empty());
+    }
+}
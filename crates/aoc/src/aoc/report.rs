@@ -0,0 +1,513 @@
+use std::fmt;
+use std::any::Any;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::alloc::{self, AllocStats, format_bytes};
+use super::bench::format_duration;
+use super::timeout::{self, TimedOut};
+use super::{Answer, Part, Solution};
+
+pub const CSV_HEADER: &str = "day,part,answer,duration_ns,status,error,allocations,allocated_bytes,peak_bytes";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format '{s}', expected json, csv or text")),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Format::Text => "text",
+            Format::Json => "json",
+            Format::Csv => "csv",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Error(String),
+    Panicked(String),
+    /// Ran past its time limit, and was abandoned.
+    Timeout,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error(_) => "error",
+            Status::Panicked(_) => "panicked",
+            Status::Timeout => "timeout",
+        }
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Status::Ok | Status::Timeout => None,
+            Status::Error(message) | Status::Panicked(message) => Some(message),
+        }
+    }
+}
+
+/// The outcome of running one part of one day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Option<Answer>,
+    pub duration: Duration,
+    pub status: Status,
+    /// Heap usage, when the `count-allocations` feature is on.
+    pub allocations: Option<AllocStats>,
+}
+
+impl PartResult {
+    /// Runs one part, catching a panic so that it is reported like any other
+    /// failure.
+    pub fn solve(solution: &dyn Solution, part: Part, input: &str) -> Self {
+        let start = Instant::now();
+        let (result, allocations) = alloc::measure(|| panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, input))));
+        let duration = start.elapsed();
+        let (answer, status) = match result {
+            Ok(Ok(answer)) => (Some(answer), Status::Ok),
+            Ok(Err(e)) => (None, Status::Error(e.to_string())),
+            Err(payload) => (None, Status::Panicked(panic_message(payload.as_ref()))),
+        };
+        Self { day: solution.day(), part, answer, duration, status, allocations }
+    }
+
+    /// Like `solve`, but gives up on the part once it has run for `limit`,
+    /// reporting it as timed out. See `timeout::run` for what happens to it.
+    pub fn solve_within(solution: &'static dyn Solution, part: Part, input: &Arc<str>, limit: Option<Duration>) -> Self {
+        let input = Arc::clone(input);
+        match timeout::run(limit, move || Self::solve(solution, part, &input)) {
+            Ok(result) => result,
+            Err(TimedOut { elapsed }) => Self {
+                day: solution.day(),
+                part,
+                answer: None,
+                duration: elapsed,
+                status: Status::Timeout,
+                allocations: None,
+            },
+        }
+    }
+
+    /// A part that failed, or could not be run at all because its input is
+    /// missing.
+    pub fn error(day: u32, part: Part, message: impl Into<String>) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            duration: Duration::ZERO,
+            status: Status::Error(message.into()),
+            allocations: None,
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.status == Status::Ok
+    }
+
+    pub fn to_text(&self) -> String {
+        match (&self.answer, &self.status) {
+            (Some(answer), Status::Ok) => match self.allocations {
+                Some(stats) => format!(
+                    "{}: {answer} ({} elapsed, {} allocations, {} allocated, {} peak)",
+                    self.part,
+                    format_duration(self.duration),
+                    stats.allocations,
+                    format_bytes(stats.bytes),
+                    format_bytes(stats.peak),
+                ),
+                None => format!("{}: {answer} ({} elapsed)", self.part, format_duration(self.duration)),
+            },
+            (_, Status::Timeout) => format!("{}: {}", self.part, TimedOut { elapsed: self.duration }),
+            (_, status) => format!("{}: {}: {}", self.part, status.name(), status.message().unwrap_or("no answer")),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let optional = |value: Option<&str>| value.map_or("null".to_string(), json_string);
+        let [allocations, bytes, peak] = self.allocation_fields().map(|field| field.unwrap_or_else(|| "null".to_string()));
+        format!(
+            concat!(
+                r#"{{"day":{},"part":{},"answer":{},"duration_ns":{},"status":"{}","error":{},"#,
+                r#""allocations":{},"allocated_bytes":{},"peak_bytes":{}}}"#,
+            ),
+            self.day,
+            self.part.number(),
+            optional(self.answer.as_ref().map(Answer::as_str)),
+            self.duration.as_nanos(),
+            self.status.name(),
+            optional(self.status.message()),
+            allocations,
+            bytes,
+            peak,
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        let [allocations, bytes, peak] = self.allocation_fields().map(Option::unwrap_or_default);
+        format!(
+            "{},{},{},{},{},{},{allocations},{bytes},{peak}",
+            self.day,
+            self.part.number(),
+            csv_field(self.answer.as_ref().map_or("", Answer::as_str)),
+            self.duration.as_nanos(),
+            self.status.name(),
+            csv_field(self.status.message().unwrap_or("")),
+        )
+    }
+
+    /// Reads back a CSV report, such as one written by a day binary run with
+    /// `--format csv`.
+    pub fn from_csv(csv: &str) -> Result<Vec<Self>, String> {
+        let mut records = csv_records(csv).into_iter();
+        match records.next() {
+            Some(header) if header.join(",") == CSV_HEADER => {}
+            _ => return Err("missing CSV header".to_string()),
+        }
+        records
+            .enumerate()
+            .map(|(i, record)| Self::from_csv_record(&record).ok_or(format!("line {}: malformed result", i + 2)))
+            .collect()
+    }
+
+    fn from_csv_record(record: &[String]) -> Option<Self> {
+        let [day, part, answer, duration, status, error, allocations, bytes, peak] = record else {
+            return None;
+        };
+        let status = match status.as_str() {
+            "ok" => Status::Ok,
+            "error" => Status::Error(error.clone()),
+            "panicked" => Status::Panicked(error.clone()),
+            "timeout" => Status::Timeout,
+            _ => return None,
+        };
+        let allocations = match (allocations.as_str(), bytes.as_str(), peak.as_str()) {
+            ("", "", "") => None,
+            _ => Some(AllocStats { allocations: allocations.parse().ok()?, bytes: bytes.parse().ok()?, peak: peak.parse().ok()? }),
+        };
+        Some(Self {
+            day: day.parse().ok()?,
+            part: Part::from_number(part.parse().ok()?)?,
+            answer: (status == Status::Ok).then(|| answer.as_str().into()),
+            duration: Duration::from_nanos(duration.parse().ok()?),
+            status,
+            allocations,
+        })
+    }
+
+    fn allocation_fields(&self) -> [Option<String>; 3] {
+        let stats = self.allocations;
+        [
+            stats.map(|stats| stats.allocations.to_string()),
+            stats.map(|stats| stats.bytes.to_string()),
+            stats.map(|stats| stats.peak.to_string()),
+        ]
+    }
+}
+
+/// Writes results as they come in. JSON output is a single array, so it is
+/// only complete once `finish` has been called.
+pub struct Reporter<W: Write> {
+    out: W,
+    format: Format,
+    written: usize,
+}
+
+impl<W: Write> Reporter<W> {
+    pub fn new(out: W, format: Format) -> Self {
+        Self { out, format, written: 0 }
+    }
+
+    /// Prints the `Day N: Title` header in text output; other formats carry
+    /// the day in every record instead.
+    pub fn start_day(&mut self, solution: &dyn Solution) -> io::Result<()> {
+        if self.format == Format::Text {
+            if self.written > 0 {
+                writeln!(self.out)?;
+            }
+            writeln!(self.out, "Day {}: {}", solution.day(), solution.title())?;
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, result: &PartResult) -> io::Result<()> {
+        match self.format {
+            Format::Text => writeln!(self.out, "{}", result.to_text())?,
+            Format::Json => {
+                let separator = if self.written == 0 { "[\n" } else { ",\n" };
+                write!(self.out, "{separator}  {}", result.to_json())?;
+            }
+            Format::Csv => {
+                if self.written == 0 {
+                    writeln!(self.out, "{CSV_HEADER}")?;
+                }
+                writeln!(self.out, "{}", result.to_csv())?;
+            }
+        }
+        self.written += 1;
+        self.out.flush()
+    }
+
+    pub fn finish(mut self) -> io::Result<()> {
+        match self.format {
+            Format::Json if self.written == 0 => writeln!(self.out, "[]")?,
+            Format::Json => writeln!(self.out, "\n]")?,
+            Format::Csv if self.written == 0 => writeln!(self.out, "{CSV_HEADER}")?,
+            _ => {}
+        }
+        self.out.flush()
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "unknown panic".to_string(),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Splits CSV text into records, undoing `csv_field`'s quoting.
+fn csv_records(csv: &str) -> Vec<Vec<String>> {
+    let (mut records, mut record, mut field) = (vec![], vec![], String::new());
+    let mut chars = csv.chars().peekable();
+    let mut quoted = false;
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            ('\r', false) => {}
+            (c, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok(day: u32, part: Part, answer: i64, nanos: u64) -> PartResult {
+        PartResult {
+            day,
+            part,
+            answer: Some(answer.into()),
+            duration: Duration::from_nanos(nanos),
+            status: Status::Ok,
+            allocations: None,
+        }
+    }
+
+    fn report(format: Format, results: &[PartResult]) -> String {
+        let mut out = Vec::new();
+        let mut reporter = Reporter::new(&mut out, format);
+        for result in results {
+            reporter.record(result).unwrap();
+        }
+        reporter.finish().unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    struct Panics;
+
+    impl Solution for Panics {
+        fn day(&self) -> u32 {
+            4
+        }
+
+        fn title(&self) -> &'static str {
+            "Panics"
+        }
+
+        fn input_path(&self) -> &'static std::path::Path {
+            std::path::Path::new("/nonexistent")
+        }
+
+        fn part_1(&self, input: &str) -> Result<Answer, crate::aoc::AocError> {
+            Ok(input.len().into())
+        }
+
+        fn part_2(&self, input: &str) -> Result<Answer, crate::aoc::AocError> {
+            panic!("index {} out of range", input.len())
+        }
+    }
+
+    #[test]
+    fn test_solve_catches_panics() {
+        assert_eq!(PartResult::solve(&Panics, Part::One, "abc").answer, Some(3.into()));
+        let result = PartResult::solve(&Panics, Part::Two, "abc");
+        assert_eq!(result.answer, None);
+        assert_eq!(result.status, Status::Panicked("index 3 out of range".to_string()));
+        assert_eq!(result.to_text(), "part_2: panicked: index 3 out of range");
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        fn day(&self) -> u32 {
+            11
+        }
+
+        fn title(&self) -> &'static str {
+            "Slow"
+        }
+
+        fn input_path(&self) -> &'static std::path::Path {
+            std::path::Path::new("/nonexistent")
+        }
+
+        fn part_1(&self, _input: &str) -> Result<Answer, crate::aoc::AocError> {
+            std::thread::sleep(Duration::from_secs(5));
+            Ok(1.into())
+        }
+
+        fn part_2(&self, input: &str) -> Result<Answer, crate::aoc::AocError> {
+            Ok(input.len().into())
+        }
+    }
+
+    #[test]
+    fn test_solve_within() {
+        let input: Arc<str> = "abc".into();
+        let limit = Some(Duration::from_millis(20));
+        let result = PartResult::solve_within(&Slow, Part::One, &input, limit);
+        assert_eq!((result.day, result.answer.as_ref(), &result.status), (11, None, &Status::Timeout));
+        assert!(result.duration >= Duration::from_millis(20));
+        assert!(result.to_text().starts_with("part_1: TIMEOUT after "));
+        assert_eq!(PartResult::from_csv(&report(Format::Csv, std::slice::from_ref(&result))), Ok(vec![result]));
+
+        assert_eq!(PartResult::solve_within(&Slow, Part::Two, &input, limit).answer, Some(3.into()));
+        assert_eq!(PartResult::solve_within(&Panics, Part::Two, &input, limit).status.name(), "panicked");
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        let allocations = Some(AllocStats { allocations: 12, bytes: 2048, peak: 1024 });
+        let results = [PartResult { allocations, ..ok(1, Part::One, 11, 1500) }, PartResult::error(1, Part::Two, "bad \"input\"")];
+        assert_eq!(
+            report(Format::Json, &results),
+            concat!(
+                "[\n",
+                r#"  {"day":1,"part":1,"answer":"11","duration_ns":1500,"status":"ok","error":null,"#,
+                r#""allocations":12,"allocated_bytes":2048,"peak_bytes":1024},"#,
+                "\n",
+                r#"  {"day":1,"part":2,"answer":null,"duration_ns":0,"status":"error","error":"bad \"input\"","#,
+                r#""allocations":null,"allocated_bytes":null,"peak_bytes":null}"#,
+                "\n]\n",
+            ),
+        );
+        assert_eq!(report(Format::Json, &[]), "[]\n");
+    }
+
+    #[test]
+    fn test_csv() {
+        let results = [ok(9, Part::Two, 6, 42), PartResult::error(10, Part::One, "no input, sorry")];
+        assert_eq!(
+            report(Format::Csv, &results),
+            concat!(
+                "day,part,answer,duration_ns,status,error,allocations,allocated_bytes,peak_bytes\n",
+                "9,2,6,42,ok,,,,\n",
+                "10,1,,0,error,\"no input, sorry\",,,\n",
+            ),
+        );
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let allocations = Some(AllocStats { allocations: 3, bytes: 300, peak: 100 });
+        let results = [
+            PartResult { allocations, ..ok(9, Part::One, 6, 42) },
+            PartResult::error(9, Part::Two, "line 2, column 1: expected digit, found 'x'\nand \"more\""),
+        ];
+        assert_eq!(PartResult::from_csv(&report(Format::Csv, &results)), Ok(results.to_vec()));
+        assert_eq!(PartResult::from_csv(&report(Format::Csv, &[])), Ok(vec![]));
+        assert!(PartResult::from_csv("9,1,6,42,ok,,,,\n").is_err());
+        assert!(PartResult::from_csv(&format!("{CSV_HEADER}\n9,1,6\n")).is_err());
+    }
+
+    #[test]
+    fn test_text() {
+        let results = [ok(1, Part::One, 11, 1500), PartResult::error(1, Part::Two, "no input")];
+        assert_eq!(report(Format::Text, &results), "part_1: 11 (1.500µs elapsed)\npart_2: error: no input\n");
+
+        let allocations = Some(AllocStats { allocations: 3, bytes: 3000, peak: 100 });
+        assert_eq!(
+            PartResult { allocations, ..ok(1, Part::One, 11, 1500) }.to_text(),
+            "part_1: 11 (1.500µs elapsed, 3 allocations, 2.9 KiB allocated, 100 B peak)",
+        );
+    }
+}
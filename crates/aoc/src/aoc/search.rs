@@ -0,0 +1,306 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// What a search should do after visiting a state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flow {
+    /// Go on to the state's neighbors.
+    Continue,
+    /// Leave the state's neighbors alone, but keep searching elsewhere.
+    Prune,
+    /// End the search here, with this state as its goal.
+    Stop,
+}
+
+/// Everything a search visited: how far each state was from the start, the
+/// way there, and the order it was visited in.
+#[derive(Clone, Debug)]
+pub struct Searched<S, C> {
+    start: S,
+    distances: HashMap<S, C>,
+    parents: HashMap<S, S>,
+    order: Vec<S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Searched<S, C> {
+    fn new(start: S) -> Self {
+        Self { start, distances: HashMap::new(), parents: HashMap::new(), order: vec![], goal: None }
+    }
+
+    // called once per state, in visit order
+    fn settle(&mut self, state: S, distance: C, parent: Option<S>) {
+        if let Some(parent) = parent {
+            self.parents.insert(state.clone(), parent);
+        }
+        self.distances.insert(state.clone(), distance);
+        self.order.push(state);
+    }
+
+    /// The distance from the start to `state`, if it was visited. For
+    /// `dfs` this is the depth in the search tree rather than the shortest
+    /// distance.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// The distance to every visited state.
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// The states from the start to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The state the search stopped at, if a visit returned `Flow::Stop`.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// The path to `goal`.
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// Every visited state, in the order it was visited.
+    pub fn visited(&self) -> &[S] {
+        &self.order
+    }
+
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+}
+
+/// Breadth first search from `start`, so states are visited in order of
+/// their number of steps from it. `visit` sees each state once, with that
+/// number.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut visit: impl FnMut(&S, usize) -> Flow,
+) -> Searched<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut searched = Searched::new(start.clone());
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0, None)]);
+    while let Some((state, distance, parent)) = queue.pop_front() {
+        searched.settle(state.clone(), distance, parent);
+        match visit(&state, distance) {
+            Flow::Continue => {}
+            Flow::Prune => continue,
+            Flow::Stop => {
+                searched.goal = Some(state);
+                break;
+            }
+        }
+        for next in neighbors(&state) {
+            if seen.insert(next.clone()) {
+                queue.push_back((next, distance + 1, Some(state.clone())));
+            }
+        }
+    }
+    searched
+}
+
+/// Depth first search from `start`, following the neighbors of each state in
+/// the order they are given. `visit` sees each state once, with its depth.
+pub fn dfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut visit: impl FnMut(&S, usize) -> Flow,
+) -> Searched<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut searched = Searched::new(start.clone());
+    let mut stack = vec![(start, 0, None)];
+    while let Some((state, depth, parent)) = stack.pop() {
+        if searched.distances.contains_key(&state) {
+            continue;
+        }
+        searched.settle(state.clone(), depth, parent);
+        match visit(&state, depth) {
+            Flow::Continue => {}
+            Flow::Prune => continue,
+            Flow::Stop => {
+                searched.goal = Some(state);
+                break;
+            }
+        }
+        // reversed, so the first neighbor is popped first
+        let next = neighbors(&state).into_iter().filter(|next| !searched.distances.contains_key(next));
+        let start = stack.len();
+        stack.extend(next.map(|next| (next, depth + 1, Some(state.clone()))));
+        stack[start..].reverse();
+    }
+    searched
+}
+
+/// Dijkstra's shortest paths from `start`, where `neighbors` gives each
+/// neighbor along with the cost of the step to it. `visit` sees each state
+/// once, in order of its distance.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    visit: impl FnMut(&S, C) -> Flow,
+) -> Searched<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbors, |_| C::default(), visit)
+}
+
+/// A* from `start`: Dijkstra's, guided towards a goal by `heuristic`, which
+/// must never overestimate the remaining cost for distances to stay exact.
+/// The goal is whichever state `visit` stops at.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut visit: impl FnMut(&S, C) -> Flow,
+) -> Searched<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut searched = Searched::new(start.clone());
+    // the best known distance and parent of every state seen so far
+    let mut best: HashMap<S, (C, Option<S>)> = HashMap::from([(start.clone(), (C::default(), None))]);
+    // states are not necessarily `Ord`, so the heap holds indices into
+    // `queued`, with the index also breaking ties first in first out
+    let mut queued = vec![(start.clone(), C::default())];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), 0))]);
+    while let Some(Reverse((_, i))) = heap.pop() {
+        let (state, distance) = queued[i].clone();
+        if searched.distances.contains_key(&state) || best[&state].0 < distance {
+            // settled already, or queued again since with a shorter distance
+            continue;
+        }
+        let parent = best[&state].1.clone();
+        searched.settle(state.clone(), distance, parent);
+        match visit(&state, distance) {
+            Flow::Continue => {}
+            Flow::Prune => continue,
+            Flow::Stop => {
+                searched.goal = Some(state);
+                break;
+            }
+        }
+        for (next, cost) in neighbors(&state) {
+            let next_distance = distance + cost;
+            match best.entry(next.clone()) {
+                Entry::Occupied(entry) if entry.get().0 <= next_distance => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert((next_distance, Some(state.clone())));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((next_distance, Some(state.clone())));
+                }
+            }
+            heap.push(Reverse((next_distance + heuristic(&next), queued.len())));
+            queued.push((next, next_distance));
+        }
+    }
+    searched
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 - 1 - 2
+    // |       |
+    // 3 ----- 4 - 5
+    fn edges(state: &u32) -> Vec<(u32, u32)> {
+        match state {
+            0 => vec![(1, 1), (3, 1)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (4, 1)],
+            3 => vec![(0, 1), (4, 5)],
+            4 => vec![(2, 1), (3, 5), (5, 1)],
+            _ => vec![(4, 1)],
+        }
+    }
+
+    fn neighbors(state: &u32) -> Vec<u32> {
+        edges(state).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let mut seen = vec![];
+        let searched = bfs(0, neighbors, |&state, distance| {
+            seen.push((state, distance));
+            Flow::Continue
+        });
+        assert_eq!(seen, [(0, 0), (1, 1), (3, 1), (2, 2), (4, 2), (5, 3)]);
+        assert_eq!(searched.visited(), [0, 1, 3, 2, 4, 5]);
+        assert_eq!(searched.path_to(&5), Some(vec![0, 3, 4, 5]));
+        assert_eq!(searched.distances().len(), 6);
+        assert_eq!(searched.goal(), None);
+
+        let searched = bfs(0, neighbors, |&state, _| if state == 2 { Flow::Stop } else { Flow::Continue });
+        assert_eq!(searched.goal_path(), Some(vec![0, 1, 2]));
+        assert_eq!(searched.distance(&5), None);
+
+        let searched = bfs(0, neighbors, |&state, _| if state == 4 { Flow::Prune } else { Flow::Continue });
+        assert_eq!(searched.path_to(&5), None);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let searched = dfs(0, neighbors, |_, _| Flow::Continue);
+        assert_eq!(searched.visited(), [0, 1, 2, 4, 3, 5]);
+        assert_eq!(searched.path_to(&3), Some(vec![0, 1, 2, 4, 3]));
+        assert_eq!(searched.distance(&3), Some(4));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let searched = dijkstra(0, edges, |_, _| Flow::Continue);
+        assert_eq!(searched.distance(&4), Some(3));
+        assert_eq!(searched.path_to(&5), Some(vec![0, 1, 2, 4, 5]));
+        assert_eq!(searched.distance(&5), Some(4));
+        assert_eq!(searched.path_to(&3), Some(vec![0, 3]));
+
+        let searched = dijkstra(0, edges, |&state, _| if state == 4 { Flow::Stop } else { Flow::Continue });
+        assert_eq!(searched.goal(), Some(&4));
+        assert_eq!(searched.distance(&5), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // on an open plane, the heuristic keeps the search on the straight line
+        let goal = (5i32, 0i32);
+        let searched = astar(
+            (0, 0),
+            |&(x, y)| [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)].map(|next| (next, 1)),
+            |&(x, y)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+            |&state, _| if state == goal { Flow::Stop } else { Flow::Continue },
+        );
+        assert_eq!(searched.distance(&goal), Some(5));
+        assert_eq!(searched.goal_path().map(|path| path.len()), Some(6));
+        assert!(searched.visited().iter().all(|&(_, y)| y == 0));
+    }
+}
@@ -0,0 +1,349 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::client::{self, Client, ClientError, YEAR};
+use super::{Answer, Part};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which direction.
+    Incorrect,
+    RateLimited { wait: Option<Duration> },
+    /// The part was already solved, so the site did not check the answer.
+    AlreadySolved,
+    /// The response did not match anything we know how to read.
+    Unknown,
+}
+
+impl Outcome {
+    pub fn parse(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Outcome::Correct
+        } else if body.contains("That's not the right answer") {
+            if body.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if body.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Incorrect
+            }
+        } else if body.contains("You gave an answer too recently") {
+            Outcome::RateLimited { wait: parse_wait(body) }
+        } else if body.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Incorrect => "incorrect",
+            Outcome::RateLimited { .. } => "rate_limited",
+            Outcome::AlreadySolved => "already_solved",
+            Outcome::Unknown => "unknown",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        let outcome = match name {
+            "correct" => Outcome::Correct,
+            "too_high" => Outcome::TooHigh,
+            "too_low" => Outcome::TooLow,
+            "incorrect" => Outcome::Incorrect,
+            "rate_limited" => Outcome::RateLimited { wait: None },
+            "already_solved" => Outcome::AlreadySolved,
+            "unknown" => Outcome::Unknown,
+            _ => return None,
+        };
+        Some(outcome)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Incorrect => write!(f, "wrong"),
+            Outcome::RateLimited { wait: Some(wait) } => write!(f, "rate limited, wait {}s", wait.as_secs()),
+            Outcome::RateLimited { wait: None } => write!(f, "rate limited"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// Reads the wait from "You have 1m 5s left to wait."
+fn parse_wait(body: &str) -> Option<Duration> {
+    let start = body.find("You have ")? + "You have ".len();
+    let end = start + body[start..].find(" left to wait")?;
+    let mut secs = 0;
+    for token in body[start..end].split_whitespace() {
+        let (number, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let number = number.parse::<u64>().ok()?;
+        secs += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the unix epoch.
+    pub at: u64,
+}
+
+/// Why an answer was not sent.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect(String),
+    KnownWrong(Outcome),
+    /// The answer is at or above one the site said was too high.
+    AboveBound(String),
+    /// The answer is at or below one the site said was too low.
+    BelowBound(String),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => write!(f, "already solved, the answer is {answer}"),
+            Refusal::KnownWrong(outcome) => write!(f, "this answer was already submitted ({outcome})"),
+            Refusal::AboveBound(bound) => write!(f, "{bound} was already too high"),
+            Refusal::BelowBound(bound) => write!(f, "{bound} was already too low"),
+        }
+    }
+}
+
+/// Every submission made, kept as one tab-separated line per attempt:
+/// `day part answer outcome timestamp`.
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut attempts = vec![];
+        for (i, line) in contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let attempt = parse_attempt(line).ok_or_else(|| io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}:{}: malformed ledger entry '{line}'", path.display(), i + 1),
+            ))?;
+            attempts.push(attempt);
+        }
+        Ok(Self { path, attempts })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Refuses answers that the recorded attempts already rule out.
+    pub fn check(&self, day: u32, part: Part, answer: &str) -> Result<(), Refusal> {
+        let attempts = self.attempts.iter().filter(|a| a.day == day && a.part == part);
+        let value = answer.parse::<i128>().ok();
+        for attempt in attempts {
+            match attempt.outcome {
+                Outcome::Correct => return Err(Refusal::AlreadyCorrect(attempt.answer.clone())),
+                outcome if outcome.is_wrong() && attempt.answer == answer => {
+                    return Err(Refusal::KnownWrong(outcome));
+                }
+                _ => {}
+            }
+            let (Some(value), Ok(bound)) = (value, attempt.answer.parse::<i128>()) else { continue };
+            match attempt.outcome {
+                Outcome::TooHigh if value >= bound => return Err(Refusal::AboveBound(attempt.answer.clone())),
+                Outcome::TooLow if value <= bound => return Err(Refusal::BelowBound(attempt.answer.clone())),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{}",
+            attempt.day,
+            attempt.part.number(),
+            attempt.answer,
+            attempt.outcome.name(),
+            attempt.at,
+        )?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.split('\t');
+    let attempt = Attempt {
+        day: fields.next()?.parse().ok()?,
+        part: Part::from_number(fields.next()?.parse().ok()?)?,
+        answer: fields.next()?.to_string(),
+        outcome: Outcome::from_name(fields.next()?)?,
+        at: fields.next()?.parse().ok()?,
+    };
+    fields.next().is_none().then_some(attempt)
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "not submitting: {refusal}"),
+            SubmitError::Client(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<ClientError> for SubmitError {
+    fn from(e: ClientError) -> Self {
+        SubmitError::Client(e)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(e: io::Error) -> Self {
+        SubmitError::Client(ClientError::Io(e))
+    }
+}
+
+impl Client {
+    /// Submits an answer unless the ledger already rules it out, and records
+    /// the attempt in the ledger.
+    pub fn submit(&self, ledger: &mut Ledger, day: u32, part: Part, answer: &Answer) -> Result<Outcome, SubmitError> {
+        client::check_unlocked(day, SystemTime::now())?;
+        ledger.check(day, part, answer.as_str()).map_err(SubmitError::Refused)?;
+
+        let level = part.number().to_string();
+        let body = self.post_form(
+            &format!("/{YEAR}/day/{day}/answer"),
+            &[("level", &level), ("answer", answer.as_str())],
+        )?;
+        let outcome = Outcome::parse(&body);
+
+        ledger.record(Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+        })?;
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aoc::test_server::TestServer;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc_submit_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn attempt(part: Part, answer: &str, outcome: Outcome) -> Attempt {
+        Attempt { day: 1, part, answer: answer.to_string(), outcome, at: 0 }
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(Outcome::parse("<p>That's the right answer! You are one gold star closer"), Outcome::Correct);
+        assert_eq!(Outcome::parse("That's not the right answer; your answer is too high."), Outcome::TooHigh);
+        assert_eq!(Outcome::parse("That's not the right answer; your answer is too low."), Outcome::TooLow);
+        assert_eq!(Outcome::parse("That's not the right answer. If you're stuck"), Outcome::Incorrect);
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently; you have to wait. You have 1m 5s left to wait."),
+            Outcome::RateLimited { wait: Some(Duration::from_secs(65)) },
+        );
+        assert_eq!(Outcome::parse("You don't seem to be solving the right level."), Outcome::AlreadySolved);
+        assert_eq!(Outcome::parse("<html>maintenance</html>"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_ledger_check() {
+        let path = temp_path("check").join("ledger.tsv");
+        let mut ledger = Ledger::load(&path).unwrap();
+        ledger.record(attempt(Part::One, "500", Outcome::TooHigh)).unwrap();
+        ledger.record(attempt(Part::One, "100", Outcome::TooLow)).unwrap();
+        ledger.record(attempt(Part::One, "300", Outcome::Incorrect)).unwrap();
+        ledger.record(attempt(Part::Two, "42", Outcome::Correct)).unwrap();
+
+        assert_eq!(ledger.check(1, Part::One, "300"), Err(Refusal::KnownWrong(Outcome::Incorrect)));
+        assert_eq!(ledger.check(1, Part::One, "600"), Err(Refusal::AboveBound("500".to_string())));
+        assert_eq!(ledger.check(1, Part::One, "100"), Err(Refusal::KnownWrong(Outcome::TooLow)));
+        assert_eq!(ledger.check(1, Part::One, "50"), Err(Refusal::BelowBound("100".to_string())));
+        assert_eq!(ledger.check(1, Part::One, "250"), Ok(()));
+        assert_eq!(ledger.check(1, Part::Two, "43"), Err(Refusal::AlreadyCorrect("42".to_string())));
+        assert_eq!(ledger.check(2, Part::One, "600"), Ok(()));
+
+        let reloaded = Ledger::load(&path).unwrap();
+        assert_eq!(reloaded.attempts(), ledger.attempts());
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = TestServer::start(|_| (200, "<article>That's not the right answer; your answer is too low.</article>".to_string()));
+        let dir = temp_path("submit");
+        let client = Client::new("abc123", &dir).with_base_url(server.url()).with_min_interval(Duration::ZERO);
+        let mut ledger = Ledger::load(dir.join("ledger.tsv")).unwrap();
+
+        let outcome = client.submit(&mut ledger, 4, Part::Two, &Answer::from(1234)).unwrap();
+        assert_eq!(outcome, Outcome::TooLow);
+        let requests = server.requests();
+        assert!(requests[0].starts_with("POST /2024/day/4/answer "));
+        assert!(requests[0].ends_with("level=2&answer=1234"));
+
+        // Lower answers are now known to be wrong, so they never reach the server.
+        let refused = client.submit(&mut ledger, 4, Part::Two, &Answer::from(1000));
+        assert!(matches!(refused, Err(SubmitError::Refused(Refusal::BelowBound(_)))));
+        assert_eq!(server.requests().len(), 1);
+        assert_eq!(Ledger::load(dir.join("ledger.tsv")).unwrap().attempts().len(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use super::visualize::Rgb;
+
+/// Which side of a region a fence is on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// A straight run of fence between two cell corners, where `(x, y)` is the
+/// top left corner of cell `(x, y)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fence {
+    pub from: (usize, usize),
+    pub to: (usize, usize),
+    pub side: Side,
+}
+
+/// The fences around a region of cells, one per straight side, so there are
+/// as many fences as the region has sides. Runs on either side of a point
+/// where the region touches itself diagonally are kept apart.
+pub fn fences(cells: &HashSet<(usize, usize)>) -> Vec<Fence> {
+    let outside = |x: Option<usize>, y: Option<usize>| match (x, y) {
+        (Some(x), Some(y)) => !cells.contains(&(x, y)),
+        _ => true,
+    };
+
+    // unit edges grouped by which side they face and the line they lie on
+    let mut edges: BTreeMap<(Side, usize), Vec<usize>> = BTreeMap::new();
+    for &(x, y) in cells {
+        if outside(Some(x), y.checked_sub(1)) {
+            edges.entry((Side::Top, y)).or_default().push(x);
+        }
+        if outside(Some(x), y.checked_add(1)) {
+            edges.entry((Side::Bottom, y + 1)).or_default().push(x);
+        }
+        if outside(x.checked_sub(1), Some(y)) {
+            edges.entry((Side::Left, x)).or_default().push(y);
+        }
+        if outside(x.checked_add(1), Some(y)) {
+            edges.entry((Side::Right, x + 1)).or_default().push(y);
+        }
+    }
+
+    let mut fences = vec![];
+    for ((side, line), mut positions) in edges {
+        positions.sort_unstable();
+        let mut start = 0;
+        for i in 1..=positions.len() {
+            if i < positions.len() && positions[i] == positions[i - 1] + 1 {
+                continue;
+            }
+            let (from, to) = (positions[start], positions[i - 1] + 1);
+            let fence = match side {
+                Side::Top | Side::Bottom => Fence { from: (from, line), to: (to, line), side },
+                Side::Left | Side::Right => Fence { from: (line, from), to: (line, to), side },
+            };
+            fences.push(fence);
+            start = i;
+        }
+    }
+    fences
+}
+
+/// A grid drawn as SVG: filled cells, paths through cell centers, fenced
+/// regions and text labels, layered in that order so labels stay readable.
+/// Shown with `{}`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Svg {
+    width: usize,
+    height: usize,
+    cell_size: u32,
+    background: Rgb,
+    cells: Vec<((usize, usize), Rgb)>,
+    paths: Vec<(Vec<(usize, usize)>, Rgb)>,
+    fences: Vec<(Fence, Rgb)>,
+    labels: Vec<((usize, usize), String, Rgb)>,
+}
+
+impl Svg {
+    /// An empty `width` by `height` cell drawing.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cell_size: 16,
+            background: Rgb(20, 20, 20),
+            cells: vec![],
+            paths: vec![],
+            fences: vec![],
+            labels: vec![],
+        }
+    }
+
+    /// Width and height of one cell in pixels.
+    pub fn cell_size(mut self, cell_size: u32) -> Self {
+        self.cell_size = cell_size.max(1);
+        self
+    }
+
+    pub fn background(mut self, color: Rgb) -> Self {
+        self.background = color;
+        self
+    }
+
+    pub fn fill(&mut self, cell: (usize, usize), color: Rgb) {
+        self.cells.push((cell, color));
+    }
+
+    pub fn label(&mut self, cell: (usize, usize), text: impl Into<String>, color: Rgb) {
+        self.labels.push((cell, text.into(), color));
+    }
+
+    /// Draws a line through the centers of `cells`, in order.
+    pub fn path(&mut self, cells: impl IntoIterator<Item = (usize, usize)>, color: Rgb) {
+        self.paths.push((cells.into_iter().collect(), color));
+    }
+
+    /// Fences in a region of cells, returning how many sides it has.
+    pub fn outline(&mut self, cells: &HashSet<(usize, usize)>, color: Rgb) -> usize {
+        let fences = fences(cells);
+        let sides = fences.len();
+        self.fences.extend(fences.into_iter().map(|fence| (fence, color)));
+        sides
+    }
+}
+
+fn hex(Rgb(r, g, b): Rgb) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape(text: &str) -> String {
+    text.chars().fold(String::with_capacity(text.len()), |mut escaped, c| {
+        match c {
+            '&' => escaped += "&amp;",
+            '<' => escaped += "&lt;",
+            '>' => escaped += "&gt;",
+            '"' => escaped += "&quot;",
+            c => escaped.push(c),
+        }
+        escaped
+    })
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let size = self.cell_size as usize;
+        let center = |n: usize| n * size + size / 2;
+        let (width, height) = (self.width * size, self.height * size);
+        writeln!(f, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#)?;
+        writeln!(f, r#"<rect width="{width}" height="{height}" fill="{}"/>"#, hex(self.background))?;
+
+        for &((x, y), color) in &self.cells {
+            writeln!(f, r#"<rect x="{}" y="{}" width="{size}" height="{size}" fill="{}"/>"#, x * size, y * size, hex(color))?;
+        }
+
+        let stroke = |fraction: usize| (size / fraction).max(1);
+        for (cells, color) in &self.paths {
+            let points: Vec<String> = cells.iter().map(|&(x, y)| format!("{},{}", center(x), center(y))).collect();
+            writeln!(
+                f,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round" stroke-opacity="0.8"/>"#,
+                points.join(" "),
+                hex(*color),
+                stroke(4),
+            )?;
+        }
+
+        for (fence, color) in &self.fences {
+            let ((x1, y1), (x2, y2)) = (fence.from, fence.to);
+            writeln!(
+                f,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}" stroke-linecap="square"/>"#,
+                x1 * size,
+                y1 * size,
+                x2 * size,
+                y2 * size,
+                hex(*color),
+                stroke(8),
+            )?;
+        }
+
+        for ((x, y), text, color) in &self.labels {
+            writeln!(
+                f,
+                r#"<text x="{}" y="{}" fill="{}" font-family="monospace" font-size="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                center(*x),
+                center(*y),
+                hex(*color),
+                size * 3 / 5,
+                escape(text),
+            )?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells(rows: &[&str], plant: char) -> HashSet<(usize, usize)> {
+        let mut cells = HashSet::new();
+        for (y, row) in rows.iter().enumerate() {
+            cells.extend(row.chars().enumerate().filter(|&(_, c)| c == plant).map(|(x, _)| (x, y)));
+        }
+        cells
+    }
+
+    #[test]
+    fn test_fences() {
+        let fences = fences(&cells(&["AA", "A."], 'A'));
+        assert_eq!(fences.len(), 6);
+        assert!(fences.contains(&Fence { from: (0, 0), to: (2, 0), side: Side::Top }));
+        assert!(fences.contains(&Fence { from: (1, 1), to: (1, 2), side: Side::Right }));
+
+        // a hole adds its own sides, and a diagonal touch does not join runs
+        let garden = ["AAAAAA", "AAABBA", "AAABBA", "ABBAAA", "ABBAAA", "AAAAAA"];
+        assert_eq!(super::fences(&cells(&garden, 'A')).len(), 12);
+        assert_eq!(super::fences(&cells(&["X.", ".X"], 'X')).len(), 8);
+        assert!(super::fences(&HashSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_render() {
+        let mut svg = Svg::new(2, 1).cell_size(10).background(Rgb::BLACK);
+        svg.fill((1, 0), Rgb::RED);
+        svg.path([(0, 0), (1, 0)], Rgb::WHITE);
+        assert_eq!(svg.outline(&HashSet::from([(0, 0)]), Rgb::GRAY), 4);
+        svg.label((0, 0), "<&>", Rgb::WHITE);
+
+        let rendered = svg.to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], r#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10" viewBox="0 0 20 10">"#);
+        assert_eq!(lines[2], r##"<rect x="10" y="0" width="10" height="10" fill="#dc322f"/>"##);
+        assert!(lines[3].starts_with(r#"<polyline points="5,5 15,5" "#));
+        assert_eq!(lines[4], r##"<line x1="0" y1="0" x2="10" y2="0" stroke="#6e6e6e" stroke-width="1" stroke-linecap="square"/>"##);
+        assert!(lines[8].ends_with(">&lt;&amp;&gt;</text>"));
+        assert_eq!(lines[9], "</svg>");
+    }
+}
@@ -0,0 +1,69 @@
+use std::fmt;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::bench::format_duration;
+
+/// A part that did not finish within its time limit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimedOut {
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TIMEOUT after {}", format_duration(self.elapsed))
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Runs `f`, giving up on it after `limit`. Without a limit `f` simply runs
+/// on the calling thread.
+///
+/// A thread cannot be stopped from outside, so `f` is abandoned rather than
+/// cancelled: it keeps running on its own thread, competing with whatever
+/// runs next, until it finishes or the process exits. A panic in `f` is
+/// passed on to the caller.
+pub fn run<T: Send + 'static>(limit: Option<Duration>, f: impl FnOnce() -> T + Send + 'static) -> Result<T, TimedOut> {
+    let Some(limit) = limit else {
+        return Ok(f());
+    };
+
+    let start = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        // the receiver is gone once the caller has given up
+        let _ = sender.send(f());
+    });
+    match receiver.recv_timeout(limit) {
+        Ok(value) => Ok(value),
+        Err(RecvTimeoutError::Timeout) => Err(TimedOut { elapsed: start.elapsed() }),
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => std::panic::resume_unwind(payload),
+            Ok(()) => unreachable!("the thread sends before it finishes"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        assert_eq!(run(None, || 1), Ok(1));
+        assert_eq!(run(Some(Duration::from_secs(5)), || 2), Ok(2));
+
+        let timed_out = run(Some(Duration::from_millis(20)), || thread::sleep(Duration::from_secs(5))).unwrap_err();
+        assert!(timed_out.elapsed >= Duration::from_millis(20));
+        assert!(timed_out.to_string().starts_with("TIMEOUT after "));
+    }
+
+    #[test]
+    fn test_run_panic() {
+        let result = std::panic::catch_unwind(|| run(Some(Duration::from_secs(5)), || panic!("boom")));
+        assert!(result.is_err());
+    }
+}
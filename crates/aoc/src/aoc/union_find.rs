@@ -0,0 +1,122 @@
+/// Disjoint sets over the elements `0..len`, which start out each in a set
+/// of their own. For grid cells, see `Grid::index_of` and `Grid::position_of`.
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    // only up to date for roots
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        Self { parents: (0..len).collect(), ranks: vec![0; len], sizes: vec![1; len], count: len }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The number of sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The element standing for `element`'s set. Every element visited on
+    /// the way is pointed straight at it, to keep later lookups short.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut current = element;
+        while current != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    /// Merges the sets of `a` and `b`, returning whether they were apart.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // hang the shallower tree under the deeper one
+        let (root, child) = if self.ranks[a] < self.ranks[b] { (b, a) } else { (a, b) };
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        self.count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in `element`'s set.
+    pub fn size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Every set, each in ascending order, ordered by their smallest element.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<usize>> + use<> {
+        let mut components: Vec<Vec<usize>> = vec![];
+        // where each root's set is in `components`
+        let mut slots = vec![usize::MAX; self.len()];
+        for element in 0..self.len() {
+            let root = self.find(element);
+            if slots[root] == usize::MAX {
+                slots[root] = components.len();
+                components.push(vec![]);
+            }
+            components[slots[root]].push(element);
+        }
+        components.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.count(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(4, 1));
+        assert!(sets.union(2, 3));
+        assert!(!sets.union(0, 4));
+        assert!(sets.connected(4, 0));
+        assert!(!sets.connected(4, 2));
+        assert_eq!(sets.size(1), 3);
+        assert_eq!(sets.size(3), 2);
+        assert_eq!(sets.size(5), 1);
+        assert_eq!(sets.count(), 3);
+        assert_eq!(sets.components().collect::<Vec<_>>(), [vec![0, 1, 4], vec![2, 3], vec![5]]);
+    }
+
+    #[test]
+    fn test_find_compresses() {
+        let mut sets = UnionFind::new(4);
+        sets.union(0, 1);
+        sets.union(2, 3);
+        sets.union(0, 2);
+        // 3 hangs under 2, which hangs under 0, until a find flattens it
+        assert_eq!(sets.parents[3], 2);
+        assert_eq!(sets.find(3), 0);
+        assert_eq!(sets.parents, [0; 4]);
+        assert_eq!(sets.ranks[0], 2);
+        assert!(UnionFind::new(0).components().next().is_none());
+    }
+}
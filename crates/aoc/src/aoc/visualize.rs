@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use super::grid::Grid;
+
+/// A snapshot of a grid simulation: one character per cell, colored by a
+/// `Palette` when shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    cells: Vec<char>,
+    pub caption: String,
+}
+
+impl Frame {
+    /// Builds a frame from rows, padding short rows with spaces.
+    pub fn from_rows<R: AsRef<[char]>>(rows: &[R]) -> Self {
+        let width = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for row in rows {
+            cells.extend_from_slice(row.as_ref());
+            cells.resize(cells.len() + width - row.as_ref().len(), ' ');
+        }
+        Self { width, cells, caption: String::new() }
+    }
+
+    /// Lays a long line of cells out over rows of `width`, for simulations
+    /// that are one dimensional.
+    pub fn wrapped(cells: impl IntoIterator<Item = char>, width: usize) -> Self {
+        let mut cells: Vec<char> = cells.into_iter().collect();
+        let width = width.clamp(1, cells.len().max(1));
+        cells.resize(cells.len().div_ceil(width) * width, ' ');
+        Self { width, cells, caption: String::new() }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        (x < self.width).then(|| self.cells.get(y * self.width + x).copied()).flatten()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[char]> {
+        self.cells.chunks(self.width.max(1))
+    }
+}
+
+impl From<&Grid<char>> for Frame {
+    fn from(grid: &Grid<char>) -> Self {
+        Self::from_rows(&grid.rows().collect::<Vec<_>>())
+    }
+}
+
+/// A 24-bit color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(110, 110, 110);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(80, 200, 80);
+    pub const YELLOW: Rgb = Rgb(230, 200, 40);
+    pub const BLUE: Rgb = Rgb(60, 120, 230);
+
+    /// Evenly spaced, fully saturated hues, for telling `n` kinds of cell
+    /// apart.
+    pub fn hue(i: usize, n: usize) -> Self {
+        let h = (i % n.max(1)) as f64 / n.max(1) as f64 * 6.0;
+        let x = 1.0 - (h % 2.0 - 1.0).abs();
+        let (r, g, b) = match h as u32 {
+            0 => (1.0, x, 0.0),
+            1 => (x, 1.0, 0.0),
+            2 => (0.0, 1.0, x),
+            3 => (0.0, x, 1.0),
+            4 => (x, 0.0, 1.0),
+            _ => (1.0, 0.0, x),
+        };
+        let channel = |c: f64| (55.0 + c * 200.0) as u8;
+        Rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// Mixes in white, with `amount` from 0 (unchanged) to 1 (white).
+    pub fn lighten(self, amount: f64) -> Self {
+        let mix = |c: u8| (c as f64 + (255.0 - c as f64) * amount.clamp(0.0, 1.0)).round() as u8;
+        Rgb(mix(self.0), mix(self.1), mix(self.2))
+    }
+}
+
+/// Which color each kind of cell is drawn in. Cells without a color use the
+/// default, which is the terminal's own foreground color when `None`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Palette {
+    colors: HashMap<char, Rgb>,
+    default: Option<Rgb>,
+}
+
+impl Palette {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Colors every character in `cells`.
+    pub fn with(mut self, cells: &str, color: Rgb) -> Self {
+        self.colors.extend(cells.chars().map(|c| (c, color)));
+        self
+    }
+
+    pub fn with_default(mut self, color: Rgb) -> Self {
+        self.default = Some(color);
+        self
+    }
+
+    pub fn color(&self, cell: char) -> Option<Rgb> {
+        self.colors.get(&cell).copied().or(self.default)
+    }
+
+    /// This palette with `other`'s colors taking precedence.
+    pub fn overlay(mut self, other: &Palette) -> Self {
+        self.colors.extend(&other.colors);
+        self.default = other.default.or(self.default);
+        self
+    }
+}
+
+/// Reads colors given as `CELLS=RRGGBB`, separated by commas, such as
+/// `#=808080,^>v<=ff0000`.
+impl FromStr for Palette {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut palette = Palette::new();
+        for entry in s.split(',').filter(|entry| !entry.is_empty()) {
+            let invalid = || format!("expected CELLS=RRGGBB, got '{entry}'");
+            let (cells, hex) = entry.rsplit_once('=').filter(|(cells, _)| !cells.is_empty()).ok_or_else(invalid)?;
+            let channel = |i: usize| hex.get(i..i + 2).and_then(|c| u8::from_str_radix(c, 16).ok());
+            let (Some(r), Some(g), Some(b), 6) = (channel(0), channel(2), channel(4), hex.len()) else {
+                return Err(invalid());
+            };
+            palette = palette.with(cells, Rgb(r, g, b));
+        }
+        Ok(palette)
+    }
+}
+
+/// Collects frames from a running simulation. Frames are built lazily, so a
+/// simulation can offer one every step and only pay for those kept.
+pub struct Recorder {
+    frames: Vec<Frame>,
+    every: usize,
+    limit: usize,
+    steps: usize,
+    dropped: usize,
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self { frames: vec![], every: 1, limit: 1000, steps: 0, dropped: 0 }
+    }
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps one frame in every `every` steps.
+    pub fn every(mut self, every: usize) -> Self {
+        self.every = every.max(1);
+        self
+    }
+
+    /// Stops keeping step frames after `limit`, to bound memory on big inputs.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Counts one step of the simulation, calling `frame` if it is kept.
+    pub fn step(&mut self, frame: impl FnOnce() -> Frame) {
+        self.steps += 1;
+        if !(self.steps - 1).is_multiple_of(self.every) {
+            return;
+        }
+        if self.frames.len() < self.limit {
+            self.frames.push(frame());
+        } else {
+            self.dropped += 1;
+        }
+    }
+
+    /// Always keeps `frame`, such as the final state.
+    pub fn keyframe(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// How many frames were left out because of the limit.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames
+    }
+}
+
+/// A simulation that can be replayed, for example by `aoc animate`.
+pub trait Visualize {
+    /// How the frames' cells are colored.
+    fn palette(&self) -> Palette {
+        Palette::new()
+    }
+
+    /// Runs the simulation from the start, offering a frame at each step.
+    fn record(&mut self, recorder: &mut Recorder);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame() {
+        let frame = Frame::from_rows(&[vec!['#', '.', '^'], vec!['.']]).with_caption("start");
+        assert_eq!((frame.width(), frame.height()), (3, 2));
+        assert_eq!(frame.get(2, 0), Some('^'));
+        assert_eq!(frame.get(1, 1), Some(' '));
+        assert_eq!(frame.get(3, 0), None);
+        assert_eq!(frame.caption, "start");
+
+        let wrapped = Frame::wrapped("00...111".chars(), 3);
+        let rows: Vec<String> = wrapped.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["00.", "..1", "11 "]);
+        assert_eq!(Frame::wrapped("".chars(), 3).height(), 0);
+    }
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::new().with("#", Rgb::GRAY).with("^>v<", Rgb::RED);
+        assert_eq!(palette.color('v'), Some(Rgb::RED));
+        assert_eq!(palette.color('.'), None);
+        assert_eq!(palette.with_default(Rgb::WHITE).color('.'), Some(Rgb::WHITE));
+        assert_eq!(Rgb::hue(0, 3), Rgb(255, 55, 55));
+        assert_eq!(Rgb(0, 100, 255).lighten(0.5), Rgb(128, 178, 255));
+    }
+
+    #[test]
+    fn test_parse_palette() {
+        let palette: Palette = "#=808080,^>v<=ff0000,==00ff00".parse().unwrap();
+        assert_eq!(palette.color('#'), Some(Rgb(128, 128, 128)));
+        assert_eq!(palette.color('<'), Some(Rgb(255, 0, 0)));
+        assert_eq!(palette.color('='), Some(Rgb(0, 255, 0)));
+        assert!("#=80808".parse::<Palette>().is_err());
+        assert!("#808080".parse::<Palette>().is_err());
+        assert!("=808080".parse::<Palette>().is_err());
+
+        let base = Palette::new().with("#.", Rgb::GRAY).with_default(Rgb::WHITE);
+        let merged = base.overlay(&palette);
+        assert_eq!((merged.color('#'), merged.color('.'), merged.color('x')), (Some(Rgb(128, 128, 128)), Some(Rgb::GRAY), Some(Rgb::WHITE)));
+    }
+
+    #[test]
+    fn test_recorder() {
+        let mut recorder = Recorder::new().every(2).limit(2);
+        let mut built = 0;
+        for i in 0..7 {
+            recorder.step(|| {
+                built += 1;
+                Frame::wrapped(i.to_string().chars(), 1)
+            });
+        }
+        recorder.keyframe(Frame::wrapped("end".chars(), 3));
+        assert_eq!(built, 2);
+        assert_eq!(recorder.dropped(), 2);
+        let frames: Vec<String> = recorder.frames().iter().map(|frame| frame.rows().flatten().collect()).collect();
+        assert_eq!(frames, ["0", "2", "end"]);
+    }
+}
@@ -0,0 +1,275 @@
+pub mod aoc {
+    pub mod alloc;
+    pub mod answers;
+    pub mod bench;
+    pub mod client;
+    pub mod error;
+    pub mod examples;
+    pub mod export;
+    pub mod grid;
+    pub mod history;
+    pub mod input;
+    pub mod log;
+    pub mod parse;
+    pub mod player;
+    pub mod point;
+    pub mod pool;
+    pub mod report;
+    pub mod search;
+    pub mod submit;
+    pub mod svg;
+    pub mod timeout;
+    pub mod union_find;
+    pub mod visualize;
+    #[cfg(test)]
+    mod test_server;
+
+    use std::any::Any;
+    use std::fmt;
+    use std::path::Path;
+    use std::process::ExitCode;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    pub use error::AocError;
+    use input::InputSource;
+    use report::{Format, PartResult, Reporter};
+    use svg::Svg;
+    use visualize::Visualize;
+
+    /// A puzzle answer, rendered the way it would be typed into the site.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Answer(String);
+
+    impl Answer {
+        pub fn as_str(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl fmt::Display for Answer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.pad(&self.0)
+        }
+    }
+
+    macro_rules! impl_answer_from {
+        ($($t:ty),*) => {
+            $(
+                impl From<$t> for Answer {
+                    fn from(value: $t) -> Self {
+                        Answer(value.to_string())
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_answer_from!(i32, i64, u32, u64, usize, String, &str);
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub enum Part {
+        One,
+        Two,
+    }
+
+    impl Part {
+        pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+        pub fn from_number(number: u32) -> Option<Self> {
+            match number {
+                1 => Some(Part::One),
+                2 => Some(Part::Two),
+                _ => None,
+            }
+        }
+
+        pub fn number(self) -> u32 {
+            match self {
+                Part::One => 1,
+                Part::Two => 2,
+            }
+        }
+    }
+
+    impl fmt::Display for Part {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "part_{}", self.number())
+        }
+    }
+
+    /// A single day's puzzle. Every day crate exposes one of these, so tooling
+    /// can run a day without knowing its concrete part functions.
+    pub trait Solution: Sync {
+        fn day(&self) -> u32;
+        fn title(&self) -> &'static str;
+        /// Where the puzzle input bundled with the day crate lives on disk.
+        fn input_path(&self) -> &'static Path;
+        fn part_1(&self, input: &str) -> Result<Answer, AocError>;
+        fn part_2(&self, input: &str) -> Result<Answer, AocError>;
+
+        /// Runs just the day's input parsing, so benchmarks can time it apart
+        /// from solving. Days without a separate parse step return `None`.
+        fn parse(&self, _input: &str) -> Option<Box<dyn Any>> {
+            None
+        }
+
+        /// The day's simulation, ready to be recorded and replayed. Days
+        /// without one return `None`.
+        fn visualize(&self, _input: &str) -> Option<Result<Box<dyn Visualize>, AocError>> {
+            None
+        }
+
+        /// A drawing of the day's answer, such as the regions or paths it
+        /// found. Days without one return `None`.
+        fn svg(&self, _input: &str) -> Option<Result<Svg, AocError>> {
+            None
+        }
+
+        fn solve(&self, part: Part, input: &str) -> Result<Answer, AocError> {
+            match part {
+                Part::One => self.part_1(input),
+                Part::Two => self.part_2(input),
+            }
+        }
+    }
+
+    pub fn format_with_time<Output: std::fmt::Display, F: FnOnce() -> Output>(f: F) -> String {
+        let now = std::time::Instant::now();
+        let result = f();
+        let elapsed = now.elapsed();
+
+        format!("{} ({} elapsed)", result, bench::format_duration(elapsed))
+    }
+
+    /// Runs and prints both parts, giving up on either once it has run for
+    /// `timeout`; see `timeout::run` for what happens to a part that does.
+    /// Day binaries go through `run_solution`, which also takes the timeout
+    /// from the command line.
+    pub fn run_parts<T, F1, F2>(input: &str, timeout: Option<Duration>, part_1: F1, part_2: F2)
+    where
+        T: std::fmt::Display + Send + 'static,
+        F1: FnOnce(&str) -> Result<T, AocError> + Send + 'static,
+        F2: FnOnce(&str) -> Result<T, AocError> + Send + 'static,
+    {
+        let input: Arc<str> = input.into();
+        println!("part_1: {}", run_part(&input, timeout, part_1));
+        println!("part_2: {}", run_part(&input, timeout, part_2));
+    }
+
+    fn run_part<T, F>(input: &Arc<str>, limit: Option<Duration>, part: F) -> String
+    where
+        T: std::fmt::Display + Send + 'static,
+        F: FnOnce(&str) -> Result<T, AocError> + Send + 'static,
+    {
+        let input = Arc::clone(input);
+        let show = |result: Result<T, AocError>| match result {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {e}"),
+        };
+        timeout::run(limit, move || format_with_time(|| show(part(&input)))).unwrap_or_else(|timed_out| timed_out.to_string())
+    }
+
+    /// Entry point for a day binary:
+    /// `cargo run -p day_N [-- [--format F] [--timeout TIME] [-v] [INPUT]]`,
+    /// where INPUT is a file, or `-` for stdin.
+    pub fn run_solution(solution: &'static dyn Solution) -> ExitCode {
+        let args = solution_args(std::env::args().skip(1)).and_then(|args| {
+            log::init(log::Level::from_verbosity(args.verbosity))?;
+            Ok(args)
+        });
+        let SolutionArgs { format, source, timeout, .. } = match args {
+            Ok(args) => args,
+            Err(message) => {
+                eprintln!("error: {message}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let input: Arc<str> = match input::load(solution, &source) {
+            Ok(input) => input.into(),
+            Err(e) => {
+                eprintln!("error: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let results = Part::ALL.map(|part| PartResult::solve_within(solution, part, &input, timeout));
+        let mut reporter = Reporter::new(std::io::stdout().lock(), format);
+        let written = results
+            .iter()
+            .try_for_each(|result| reporter.record(result))
+            .and_then(|()| reporter.finish());
+        match written {
+            Ok(()) if results.iter().all(PartResult::is_ok) => ExitCode::SUCCESS,
+            Ok(()) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("error: {e}");
+                ExitCode::FAILURE
+            }
+        }
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    struct SolutionArgs {
+        format: Format,
+        source: InputSource,
+        verbosity: usize,
+        timeout: Option<Duration>,
+    }
+
+    fn solution_args(mut args: impl Iterator<Item = String>) -> Result<SolutionArgs, String> {
+        let mut parsed = SolutionArgs::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" => parsed.format = args.next().ok_or("--format needs a value")?.parse()?,
+                "--timeout" => {
+                    let timeout = args.next().ok_or("--timeout needs a value")?;
+                    let invalid = || format!("--timeout expects a duration like 1s or 500ms, got '{timeout}'");
+                    parsed.timeout = Some(bench::parse_duration(&timeout).ok_or_else(invalid)?);
+                }
+                "-v" | "-vv" | "-vvv" => parsed.verbosity += arg.len() - 1,
+                _ => parsed.source = InputSource::from_arg(&arg),
+            }
+        }
+        Ok(parsed)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_answer_from() {
+            assert_eq!(Answer::from(-3).as_str(), "-3");
+            assert_eq!(Answer::from(12_i64).to_string(), "12");
+            assert_eq!(Answer::from("abc"), Answer::from(String::from("abc")));
+        }
+
+        #[test]
+        fn test_part_from_number() {
+            assert_eq!(Part::from_number(1), Some(Part::One));
+            assert_eq!(Part::from_number(2), Some(Part::Two));
+            assert_eq!(Part::from_number(3), None);
+            assert_eq!(Part::Two.to_string(), "part_2");
+        }
+
+        #[test]
+        fn test_solution_args() {
+            let args = |args: &str| solution_args(args.split_whitespace().map(String::from));
+            assert_eq!(args(""), Ok(SolutionArgs::default()));
+            assert_eq!(args("--format csv -"), Ok(SolutionArgs { format: Format::Csv, source: InputSource::Stdin, ..SolutionArgs::default() }));
+            assert_eq!(
+                args("-v input.txt -v --timeout 2s"),
+                Ok(SolutionArgs {
+                    source: InputSource::File("input.txt".into()),
+                    verbosity: 2,
+                    timeout: Some(Duration::from_secs(2)),
+                    ..SolutionArgs::default()
+                }),
+            );
+            assert!(args("--format").is_err());
+            assert!(args("--format xml input.txt").is_err());
+            assert!(args("--timeout soon").is_err());
+        }
+    }
+}
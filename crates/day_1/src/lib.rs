@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::any::Any;
+use std::path::Path;
+
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::parse;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn parse(&self, input: &str) -> Option<Box<dyn Any>> {
+        Some(Box::new(parse_input(input)))
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), AocError> {
+    let pairs = parse::lines(input, |line| match parse::ints::<i32>(line, "a number")?[..] {
+        [x1, x2] => Ok((x1, x2)),
+        _ => Err(AocError::parse_at(line, line, "two numbers")),
+    })?;
+    Ok(pairs.into_iter().unzip())
+}
+
+fn get_counts(list: &[i32]) -> HashMap<i32, i32> {
+    let mut counts = HashMap::new();
+    for item in list.iter() {
+        let entry = counts.entry(*item).or_insert(0);
+        *entry += 1;
+    }
+    counts
+}
+
+fn part_1(input: &str) -> Result<i32, AocError> {
+    let (mut l1, mut l2) = parse_input(input)?;
+    l1.sort();
+    l2.sort();
+
+    let mut sum = 0;
+    for (x1, x2) in l1.iter().zip(l2.iter()) {
+        let dist = (x1 - x2).abs();
+        sum += dist;
+    }
+
+    Ok(sum)
+}
+
+fn part_2(input: &str) -> Result<i32, AocError> {
+    let (l1, l2) = parse_input(input)?;
+    let right_counts = get_counts(&l2);
+    
+    let mut sum = 0;
+    for item in l1 {
+        sum += item * right_counts.get(&item).unwrap_or(&0);
+    }
+
+    Ok(sum)
+}
+
+advent_of_code_2024::examples! {
+    Day1,
+    "example.txt" => { part_1: 11, part_2: 31 },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let test_input = "1 2
+        3 4
+        5 6";
+        
+        let result: (Vec<i32>, Vec<i32>) = parse_input(test_input).unwrap();
+        let expected = (vec![1, 3, 5], vec![2, 4, 6]);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_get_counts() {
+        let test_data = vec![1, 1, 3, 3, 3, 2];
+        let result = get_counts(&test_data);
+        let mut expected = HashMap::new();
+        expected.insert(1, 2);
+        expected.insert(3, 3);
+        expected.insert(2, 1);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("1 2\n3 4x\n5 6").unwrap_err();
+        assert_eq!(error, AocError::parse(2, 3, "4x", "a number"));
+        assert_eq!(parse_input("1 2\n3\n").unwrap_err(), AocError::parse(2, 1, "3", "two numbers"));
+    }
+}
@@ -0,0 +1,159 @@
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::grid::Grid;
+use advent_of_code_2024::aoc::parse;
+use advent_of_code_2024::aoc::search::{self, Flow};
+use advent_of_code_2024::aoc::svg::Svg;
+use advent_of_code_2024::aoc::visualize::Rgb;
+use advent_of_code_2024::debug;
+use std::any::Any;
+use std::path::Path;
+
+fn parse_input(input: &str) -> Result<Grid<u32>, AocError> {
+    parse::digit_grid(input, "a height from 0 to 9")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u32 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Hoof It"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn parse(&self, input: &str) -> Option<Box<dyn Any>> {
+        Some(Box::new(parse_input(input)))
+    }
+
+    fn svg(&self, input: &str) -> Option<Result<Svg, AocError>> {
+        Some(draw(input))
+    }
+}
+
+type Trail = Vec<(usize, usize)>;
+
+// the positions one step higher than `position`
+fn uphill(data: &Grid<u32>, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    let height = data[position];
+    data.neighbors(position).filter(move |&next| data[next] == height + 1)
+}
+
+// returns the number of distinct trails from a given trailhead; `trail` is
+// the way here, and `observe` sees each trail that reaches a 9
+fn traverse(
+    position: (usize, usize),
+    data: &Grid<u32>,
+    trail: &mut Trail,
+    observe: &mut impl FnMut(&Trail),
+) -> i32 {
+    let curr_height = data[position];
+    trail.push(position);
+
+    // end case
+    if curr_height == 9 {
+        observe(trail);
+        trail.pop();
+        return 1;
+    }
+
+    // try to move all directions
+    let mut sum = 0;
+    for next in uphill(data, position) {
+        sum += traverse(next, data, trail, observe);
+    }
+
+    trail.pop();
+    sum
+}
+
+fn part_1(input: &str) -> Result<i32, AocError> {
+    let data = parse_input(input)?;
+    let mut sum = 0;
+    for (position, &height) in data.iter() {
+        if height == 0 {
+            // the score is the number of 9s reachable at all
+            let mut score = 0;
+            search::bfs(position, |&position| uphill(&data, position), |&position, _| {
+                if data[position] == 9 {
+                    score += 1;
+                }
+                Flow::Continue
+            });
+            debug!("{position:?}: score = {score}");
+            sum += score;
+        }
+    }
+    Ok(sum)
+}
+
+fn part_2(input: &str) -> Result<i32, AocError> {
+    let data = parse_input(input)?;
+    let mut sum = 0;
+    for (position, &height) in data.iter() {
+        if height == 0 {
+            let score = traverse(position, &data, &mut vec![], &mut |_| {});
+            debug!("{position:?}: score = {score}");
+            sum += score;
+        }
+    }
+    Ok(sum)
+}
+
+/// Draws the map shaded by height, with every distinct trail colored by its
+/// trailhead.
+fn draw(input: &str) -> Result<Svg, AocError> {
+    let data = parse_input(input)?;
+    let mut svg = Svg::new(data.width(), data.height()).cell_size(24);
+    let mut trailheads = vec![];
+    for (position, &height) in data.iter() {
+        svg.fill(position, Rgb(30, 30, 30).lighten(height as f64 / 15.0));
+        svg.label(position, height.to_string(), Rgb::WHITE);
+        if height == 0 {
+            trailheads.push(position);
+        }
+    }
+
+    for (i, &position) in trailheads.iter().enumerate() {
+        let color = Rgb::hue(i, trailheads.len());
+        traverse(position, &data, &mut vec![], &mut |trail| {
+            svg.path(trail.iter().copied(), color);
+        });
+    }
+    Ok(svg)
+}
+
+advent_of_code_2024::examples! {
+    Day10,
+    "example.txt" => { part_1: 36, part_2: 81 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let parsed = parse_input("123\n456").unwrap();
+        assert_eq!(parsed.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse_input("123\n4.6").unwrap_err(), AocError::parse(2, 2, ".", "a height from 0 to 9"));
+        assert_eq!(parse_input("").unwrap_err(), AocError::parse(1, 1, "", "a grid of at least one cell"));
+    }
+}
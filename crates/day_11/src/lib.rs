@@ -0,0 +1,114 @@
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::parse;
+use std::collections::HashMap;
+use std::any::Any;
+use std::path::Path;
+
+fn blink(stone: i64) -> (i64, Option<i64>) {
+    if stone == 0 {
+        return (1, None);
+    }
+
+    let stone_string = format!("{stone}");
+    if stone_string.len() % 2 == 0 {
+        let left = stone_string[0..stone_string.len()/2].parse::<i64>().unwrap();
+        let right = stone_string[stone_string.len()/2..stone_string.len()].parse::<i64>().unwrap();
+        return (left, Some(right))
+    }
+
+    (stone * 2024, None)
+}
+
+fn parse_input(input: &str) -> Result<Vec<i64>, AocError> {
+    parse::ints(input, "a stone number")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u32 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn parse(&self, input: &str) -> Option<Box<dyn Any>> {
+        Some(Box::new(parse_input(input)))
+    }
+}
+
+fn part_1(input: &str) -> Result<i64, AocError> {
+    let mut stones = parse_input(input)?;
+
+    for _ in 0..25 {
+        let mut i = 0;
+        while i < stones.len() {
+            let stone = stones[i];
+            let result = blink(stone);
+            stones[i] = result.0;
+            if let Some(right_stone) = result.1 {
+                stones.insert(i + 1, right_stone);
+                i += 1;
+            }
+            i += 1;
+        }    
+    }
+    Ok(stones.len() as i64)
+}
+
+fn part_2(input: &str) -> Result<i64, AocError> {
+    let data = parse_input(input)?;
+
+    let mut stone_counts: HashMap<i64, i64> = data.iter().map(|stone| (*stone, 1)).collect::<HashMap<_, _>>();
+    for _ in 0..75 {
+        let mut next_stone_counts = HashMap::new();
+        for (&stone, &count) in stone_counts.iter() {
+            let (left_stone, right_stone) = blink(stone);        
+            *next_stone_counts.entry(left_stone).or_insert(0) += count;
+            if let Some(right_stone) = right_stone {
+                *next_stone_counts.entry(right_stone).or_insert(0) += count;
+            }
+        }
+        stone_counts = next_stone_counts;
+    }
+    Ok(stone_counts.values().sum())
+}
+
+advent_of_code_2024::examples! {
+    Day11,
+    "example.txt" => { part_1: 55312 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blink() {
+        assert_eq!(blink(0), (1, None));
+        assert_eq!(blink(1), (2024, None));
+        assert_eq!(blink(10), (1, Some(0)));
+        assert_eq!(blink(2024), (20, Some(24)));
+        assert_eq!(blink(1000), (10, Some(0)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse_input("125 17").unwrap(), vec![125, 17]);
+        assert_eq!(parse_input("125 1x7").unwrap_err(), AocError::parse(1, 5, "1x7", "a stone number"));
+    }
+}
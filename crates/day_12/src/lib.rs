@@ -0,0 +1,176 @@
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::grid::Grid;
+use advent_of_code_2024::aoc::point::{Direction, Point};
+use advent_of_code_2024::aoc::svg::Svg;
+use advent_of_code_2024::aoc::union_find::UnionFind;
+use advent_of_code_2024::aoc::visualize::{Frame, Palette, Recorder, Rgb, Visualize};
+use std::collections::HashSet;
+use std::any::Any;
+use std::path::Path;
+
+fn parse_input(input: &str) -> Result<Grid<char>, AocError> {
+    Grid::parse(input, "a plant from A to Z", |c| c.is_ascii_uppercase().then_some(c))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> u32 {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Garden Groups"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn parse(&self, input: &str) -> Option<Box<dyn Any>> {
+        Some(Box::new(parse_input(input)))
+    }
+
+    fn visualize(&self, input: &str) -> Option<Result<Box<dyn Visualize>, AocError>> {
+        Some(parse_input(input).map(|garden| Box::new(FloodFill(garden)) as Box<dyn Visualize>))
+    }
+
+    fn svg(&self, input: &str) -> Option<Result<Svg, AocError>> {
+        Some(draw(input))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Specs {
+    area: i32,
+    perimeter: i32,
+    sides: i32,
+}
+
+// outside the garden counts as '.', which no plant matches
+fn plant_at(garden: &Grid<char>, point: Point) -> char {
+    garden.checked_position(point).map_or('.', |position| garden[position])
+}
+
+fn specs(garden: &Grid<char>, plots: &[(usize, usize)]) -> Specs {
+    let plant = garden[plots[0]];
+    let same = |point: Point| plant_at(garden, point) == plant;
+
+    let mut perimeter = 0;
+    let mut sides = 0;
+    for &plot in plots {
+        let pos = Point::from(plot);
+        for direction in Direction::ALL {
+            if same(pos + direction) {
+                continue;
+            }
+            // A piece of fence. Pieces in a row facing the same way make up
+            // one side, so only the piece at the side's end counts as new:
+            // the plot to its left either is another region, or is in this
+            // one but unfenced on this side.
+            let beside = pos + direction.turn_left();
+            let continues_side = same(beside) && !same(beside + direction);
+            if !continues_side {
+                sides += 1;
+            }
+            perimeter += 1;
+        }
+    }
+
+    Specs { area: plots.len() as i32, perimeter, sides }
+}
+
+/// Shows the regions being found one plot at a time, from the top left.
+struct FloodFill(Grid<char>);
+
+impl Visualize for FloodFill {
+    fn palette(&self) -> Palette {
+        ('A'..='Z').fold(Palette::new().with(".", Rgb(60, 60, 60)), |palette, plant| {
+            palette.with(&plant.to_string(), plant_color(plant))
+        })
+    }
+
+    fn record(&mut self, recorder: &mut Recorder) {
+        let garden = &self.0;
+        let regions = regions(garden);
+        let mut shown = Grid::new(garden.width(), garden.height(), '.');
+        let mut visited = 0;
+        for plots in &regions {
+            for &plot in plots {
+                shown[plot] = garden[plot];
+                visited += 1;
+                recorder.step(|| Frame::from(&shown).with_caption(format!("{visited} plots visited")));
+            }
+        }
+        recorder.keyframe(Frame::from(&shown).with_caption(format!("done: {} regions", regions.len())));
+    }
+}
+
+// neighbouring letters get far apart hues
+fn plant_color(plant: char) -> Rgb {
+    Rgb::hue(plant as usize * 7, 26)
+}
+
+// the plots of each region, grouped without marking the garden
+fn regions(garden: &Grid<char>) -> Vec<Vec<(usize, usize)>> {
+    let mut sets = UnionFind::new(garden.width() * garden.height());
+    for (plot, &plant) in garden.iter() {
+        for next in garden.neighbors(plot) {
+            if garden[next] == plant {
+                sets.union(garden.index_of(plot).unwrap(), garden.index_of(next).unwrap());
+            }
+        }
+    }
+    sets.components()
+        .map(|region| region.into_iter().filter_map(|index| garden.position_of(index)).collect())
+        .collect()
+}
+
+/// Draws every region in its plant's color, fenced along each of its sides.
+fn draw(input: &str) -> Result<Svg, AocError> {
+    let garden = parse_input(input)?;
+    let mut svg = Svg::new(garden.width(), garden.height());
+    for plots in regions(&garden) {
+        let plant = garden[plots[0]];
+        let color = plant_color(plant);
+        for &plot in &plots {
+            svg.fill(plot, color.lighten(0.6));
+            svg.label(plot, plant.to_string(), Rgb::BLACK);
+        }
+        svg.outline(&plots.iter().copied().collect::<HashSet<_>>(), color);
+    }
+    Ok(svg)
+}
+
+fn part_1(input: &str) -> Result<i32, AocError> {
+    let garden = parse_input(input)?;
+    let mut sum = 0;
+    for plots in regions(&garden) {
+        let specs = specs(&garden, &plots);
+        sum += specs.area * specs.perimeter;
+    }
+    Ok(sum)
+}
+
+fn part_2(input: &str) -> Result<i32, AocError> {
+    let garden = parse_input(input)?;
+    let mut sum = 0;
+    for plots in regions(&garden) {
+        let specs = specs(&garden, &plots);
+        sum += specs.area * specs.sides;
+    }
+    Ok(sum)
+}
+
+advent_of_code_2024::examples! {
+    Day12,
+    "example.txt" => { part_1: 1184, part_2: 368 },
+}
@@ -0,0 +1,145 @@
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::parse;
+use std::any::Any;
+use std::path::Path;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Red-Nosed Reports"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn parse(&self, input: &str) -> Option<Box<dyn Any>> {
+        Some(Box::new(parse_input(input)))
+    }
+}
+
+fn increasing(data: &[i32]) -> bool {
+    for i in 0..data.len().saturating_sub(1) {
+        let curr = data[i];
+        let next = data[i+1];
+
+        if next <= curr {
+            return false;
+        }
+    }
+    true
+}
+
+fn decreasing(data: &[i32]) -> bool {
+    for i in 0..data.len().saturating_sub(1) {
+        let curr = data[i];
+        let next = data[i+1];
+
+        if next >= curr {
+            return false;
+        }
+    }
+    true
+}
+
+// returns true if abs diff between all adjacent numbers is 3 or less
+fn bounded(data: &[i32]) -> bool {
+    for i in 0..data.len().saturating_sub(1) {
+        let curr = data[i];
+        let next = data[i+1];
+
+        if (next - curr).abs() > 3 {
+            return false;
+        }
+    }
+    true
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, AocError> {
+    parse::lines(input, |line| parse::ints(line, "a number"))
+}
+
+fn part_1(input: &str) -> Result<i32, AocError> {
+    let data = parse_input(input)?;
+    Ok(data.into_iter().filter(|row| is_safe(row)).count() as i32)
+}
+
+fn is_safe(row: &[i32]) -> bool {
+    (increasing(row) || decreasing(row)) && bounded(row)
+}
+
+fn is_close_to_safe(row: &[i32]) -> bool {
+    if is_safe(row) {
+        return true;
+    }
+
+    let mut row = row.to_vec();
+    for i in 0..row.len() {
+        let item = row.remove(i);     
+        if is_safe(&row) {
+            return true;
+        }
+        row.insert(i, item);
+    }
+
+    false
+}
+
+fn part_2(input: &str) -> Result<i32, AocError> {
+    let data = parse_input(input)?;
+    Ok(data.into_iter().filter(|row| is_close_to_safe(row)).count() as i32)
+}
+
+advent_of_code_2024::examples! {
+    Day2,
+    "example.txt" => { part_1: 2, part_2: 4 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_increasing() {
+        assert!(increasing(&[1, 2, 3, 4, 5]));
+        assert!(!increasing(&[1, 2, 2, 3, 4, 5]));
+        assert!(!increasing(&[5, 4, 3, 2, 1]));
+        assert!(increasing(&[]));
+    }
+
+    #[test]
+    fn test_decreasing() {
+        assert!(!decreasing(&[1, 2, 3, 4, 5]));
+        assert!(!decreasing(&[1, 2, 2, 3, 4, 5]));
+        assert!(decreasing(&[5, 4, 3, 2, 1]));
+        assert!(decreasing(&[]));
+    }
+
+    #[test]
+    fn test_bounded() {
+        assert!(bounded(&[1, 2, 3, 4, 5]));
+        assert!(bounded(&[1, 2, 2, 3, 4, 5]));
+        assert!(bounded(&[5, 4, 3, 2, 1]));
+        assert!(bounded(&[]));
+        assert!(!bounded(&[1, 5]));
+        assert!(!bounded(&[1, 2, 3, -1, 1]));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse_input("7 6 4\n1 2 -\n").unwrap_err(), AocError::parse(2, 5, "-", "a number"));
+    }
+}
@@ -0,0 +1,71 @@
+use regex::Regex;
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use std::path::Path;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn day(&self) -> u32 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Mull It Over"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+}
+
+fn parse_int(s: &str) -> i64 {
+    s.parse::<i64>().unwrap()
+}
+
+fn part_1(input: &str) -> Result<i64, AocError> {
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+
+    let mut sum: i64 = 0;
+    for (_, [a, b]) in re.captures_iter(input).map(|c: regex::Captures| c.extract()) {
+        sum += parse_int(a) * parse_int(b);
+    }
+
+    Ok(sum)
+}
+
+const MUL_RE: &str = r"mul\((\d{1,3}),(\d{1,3})\)";
+// must have same number of capture groups, as the above regex, so we add a dummy caputre group
+const DO_RE: &str = r"(do\(\))()";
+const DONT_RE: &str = r"(don't\(\))()";
+
+fn part_2(input: &str) -> Result<i64, AocError> {
+    let re_string = format!("{}|{}|{}", MUL_RE, DO_RE, DONT_RE);
+    let re = Regex::new(re_string.as_str()).unwrap();
+
+    let mut sum: i64 = 0;
+    let mut enabled = true;
+    for (_, [a, b]) in re.captures_iter(input).map(|c: regex::Captures| c.extract()) {
+        // if "do" or "don't", will be present in the first capture group
+        match a {
+            "don't()" => enabled = false,
+            "do()" => enabled = true,
+            _ => if enabled { sum += parse_int(a) * parse_int(b) },
+        };
+    }
+
+    Ok(sum)
+}
+
+advent_of_code_2024::examples! {
+    Day3,
+    "example.txt" => { part_1: 161 },
+    "example_2.txt" => { part_2: 48 },
+}
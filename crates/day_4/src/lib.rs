@@ -0,0 +1,207 @@
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::grid::Grid;
+use std::any::Any;
+use std::path::Path;
+use itertools::Itertools;
+
+fn parse_input(input: &str) -> Result<Grid<char>, AocError> {
+    input.parse()
+}
+
+type PatternItem = ((usize, usize), char);
+type Pattern = Vec<PatternItem>;
+
+fn xmas_patterns() -> Vec<Pattern> {
+    let x = |positions: Vec<(usize, usize)>| {
+        vec![
+            vec![
+                (positions[0], 'X'),
+                (positions[1], 'M'),
+                (positions[2], 'A'),
+                (positions[3], 'S'),
+            ],
+            vec![
+                (positions[3], 'X'),
+                (positions[2], 'M'),
+                (positions[1], 'A'),
+                (positions[0], 'S'),
+            ]
+        ]
+    };
+    vec![
+        x(vec![
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (3, 0),
+        ]),
+        x(vec![
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (0, 3),
+        ]),
+        x(vec![
+            (0, 0),
+            (1, 1),
+            (2, 2),
+            (3, 3),
+        ]),
+        x(vec![
+            (3, 0),
+            (2, 1),
+            (1, 2),
+            (0, 3),
+        ]),
+    ]
+        .into_iter().flatten().collect_vec()
+}
+
+fn x_mas_patterns() -> Vec<Pattern> {
+    vec![
+        vec![
+            ((0, 0), 'M'),
+            ((1, 1), 'A'),
+            ((2, 2), 'S'),
+            ((2, 0), 'M'),
+            ((0, 2), 'S'),
+        ],
+        vec![
+            ((0, 0), 'M'),
+            ((1, 1), 'A'),
+            ((2, 2), 'S'),
+            ((2, 0), 'S'),
+            ((0, 2), 'M'),
+        ],
+        vec![
+            ((0, 0), 'S'),
+            ((1, 1), 'A'),
+            ((2, 2), 'M'),
+            ((2, 0), 'M'),
+            ((0, 2), 'S'),
+        ],
+         vec![
+            ((0, 0), 'S'),
+            ((1, 1), 'A'),
+            ((2, 2), 'M'),
+            ((2, 0), 'S'),
+            ((0, 2), 'M'),
+        ],
+    ]
+}
+
+fn match_pattern_at_position(pattern: &Pattern, position: (usize, usize), data: &Grid<char>) -> bool {
+    for pattern_item in pattern.iter() {
+        if !match_pattern_item_at_position(*pattern_item, position, data) {
+            return false;
+        }
+    }
+    true
+}
+
+fn match_pattern_item_at_position(pattern_item: PatternItem, position: (usize, usize), data: &Grid<char>) -> bool {
+    let (x, y) = position;
+    let (x, y) = (x + pattern_item.0.0, y + pattern_item.0.1);
+
+    // out of bounds never matches
+    data.get((x, y)) == Some(&pattern_item.1)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn day(&self) -> u32 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Ceres Search"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn parse(&self, input: &str) -> Option<Box<dyn Any>> {
+        Some(Box::new(parse_input(input)))
+    }
+}
+
+fn part_1(input: &str) -> Result<i32, AocError> {
+    let data = parse_input(input)?;
+    let patterns = xmas_patterns();
+    let mut sum = 0;
+    for position in data.positions() {
+        for pattern in patterns.iter() {
+            if match_pattern_at_position(pattern, position, &data) {
+                sum += 1;
+            }
+        }
+    }
+    Ok(sum)
+}
+
+fn part_2(input: &str) -> Result<i32, AocError> {
+    let data = parse_input(input)?;
+    let patterns = x_mas_patterns();
+    let mut sum = 0;
+    for position in data.positions() {
+        for pattern in patterns.iter() {
+            if match_pattern_at_position(pattern, position, &data) {
+                sum += 1;
+            }
+        }
+    }
+    Ok(sum)
+}
+
+advent_of_code_2024::examples! {
+    Day4,
+    "example.txt" => { part_1: 18, part_2: 9 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_pattern_item_at_position() {
+        let pattern_item = ((0, 0), 'X');
+        // (0, 0)
+        assert!(match_pattern_item_at_position(pattern_item, (0, 0), &"X".parse().unwrap()));
+        assert!(!match_pattern_item_at_position(pattern_item, (0, 0), &"M".parse().unwrap()));
+
+        // (1, 1)
+        let pattern_item = ((1, 1), 'X');
+        assert!(match_pattern_item_at_position(pattern_item, (0, 0), &"..\n.X".parse().unwrap()));
+        assert!(!match_pattern_item_at_position(pattern_item, (0, 0), &"..\n.M".parse().unwrap()));
+        assert!(!match_pattern_item_at_position(pattern_item, (1, 0), &"..\n.X".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_match_pattern_at_position() {
+        let pattern = vec![
+            ((0, 0), 'X'),
+            ((1, 1), 'M'),
+        ];
+        let data = "X.\n.M".parse().unwrap();
+        assert!(match_pattern_at_position(&pattern, (0, 0), &data));
+        let data = "X.\n.X".parse().unwrap();
+        assert!(!match_pattern_at_position(&pattern, (0, 0), &data));
+    }
+
+    #[test]
+    fn test_wide_grid() {
+        assert_eq!(part_1("..XMAS").unwrap(), 1);
+        assert_eq!(part_1("..S\n..A\n..M\n..X").unwrap(), 1);
+    }
+}
+
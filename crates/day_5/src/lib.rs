@@ -0,0 +1,144 @@
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::parse;
+use std::collections::HashSet;
+use std::any::Any;
+use std::path::Path;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct Rule(i32, i32);
+
+struct Data {
+    updates: Vec<Vec<i32>>,
+    rules: Vec<Rule>,
+}
+
+fn parse_input(input: &str) -> Result<Data, AocError> {
+    let page = |s: &str| parse::value(s, "a page number");
+    let sections = parse::sections(input);
+    let &[rules, updates] = sections.as_slice() else {
+        // point just past the rules, where the blank line should be
+        let end = sections.first().map_or(&input[..0], |first| &first[first.len()..]);
+        return Err(AocError::parse_at(input, end, "rules and updates separated by a blank line"));
+    };
+
+    let rules = parse::key_values(rules, "|", "a rule like '47|53'", page, page)
+        .map_err(|error| error.within(input, rules))?
+        .into_iter()
+        .map(|(first, second)| Rule(first, second))
+        .collect();
+
+    let updates = parse::lines(updates, |line| parse::separated(line, ",", "a page number"))
+        .map_err(|error| error.within(input, updates))?;
+
+    Ok(Data {
+        updates,
+        rules,
+    })
+}
+
+fn aggregate_rules(rules: &[Rule]) -> HashSet<Rule> {
+    let mut set = HashSet::new();
+    for rule in rules.iter() {
+        set.insert(Rule(rule.0, rule.1));
+    }
+    set
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn day(&self) -> u32 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "Print Queue"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn parse(&self, input: &str) -> Option<Box<dyn Any>> {
+        Some(Box::new(parse_input(input)))
+    }
+}
+
+fn part_1(input: &str) -> Result<i32, AocError> {
+    let Data { updates, rules } = parse_input(input)?;
+    let rules_set = aggregate_rules(&rules);
+
+    let mut sum = 0;
+    'outer: for update in updates.into_iter() {
+        for i in 0..update.len().saturating_sub(1) {
+            let rule = Rule(update[i], update[i + 1]);
+            if !rules_set.contains(&rule) {
+                continue 'outer;
+            }
+        }
+        sum += update[update.len() / 2];
+    }
+
+    Ok(sum)
+}
+
+// returns true if the update required reordering
+fn reorder(update: &mut [i32], rule_set: &HashSet<Rule>) -> bool {
+    let mut updated = false;
+    'outer: loop {
+        for i in 0..update.len().saturating_sub(1) {
+            let rule = Rule(update[i], update[i + 1]);
+            if rule_set.get(&rule).is_none() {
+                (update[i], update[i + 1]) = (update[i + 1], update[i]);
+                updated = true;
+                continue 'outer;
+            }
+        }
+        break;
+    }
+    updated
+}
+
+fn part_2(input: &str) -> Result<i32, AocError> {
+    let Data { updates, rules } = parse_input(input)?;
+    let rules_set = aggregate_rules(&rules);
+
+    let mut sum = 0;
+    for mut update in updates.into_iter() {
+        if reorder(&mut update, &rules_set) {
+            sum += update[update.len() / 2];
+        }
+    }
+
+    Ok(sum)
+}
+
+advent_of_code_2024::examples! {
+    Day5,
+    "example.txt" => { part_1: 143, part_2: 123 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("47|53\n97-13\n\n75,47\n").err();
+        assert_eq!(error, Some(AocError::parse(2, 1, "97-13", "a rule like '47|53'")));
+        let error = parse_input("47|53\n\n75,47\n61,x\n").err();
+        assert_eq!(error, Some(AocError::parse(4, 4, "x", "a page number")));
+        let error = parse_input("47|53\n75,47\n").err();
+        assert_eq!(error, Some(AocError::parse(2, 6, "", "rules and updates separated by a blank line")));
+        let error = parse_input("").err();
+        assert_eq!(error, Some(AocError::parse(1, 1, "", "rules and updates separated by a blank line")));
+    }
+}
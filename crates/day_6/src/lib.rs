@@ -0,0 +1,217 @@
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::grid::Grid;
+use advent_of_code_2024::aoc::point::Direction;
+use advent_of_code_2024::aoc::visualize::{Frame, Palette, Recorder, Rgb, Visualize};
+use std::collections::HashSet;
+use std::any::Any;
+use std::path::Path;
+
+type Position = (usize, usize);
+
+struct LabSim {
+    states: Grid<char>,
+    visited: HashSet<Position>,
+    guard: Position,
+}
+
+impl LabSim {
+    fn from_input(input: &str) -> Result<Self, AocError> {
+        let states: Grid<char> = input.parse()?;
+        let Some(guard) = states.find(|&c| c == '^') else {
+            return Err(AocError::parse(states.height() + 1, 1, "", "a guard '^' somewhere in the map"));
+        };
+        Ok(Self {
+            states,
+            visited: HashSet::new(),
+            guard,
+        })
+    }
+
+    fn run_loop_sim(&mut self) -> i32 {
+        self.run_loop_sim_with(|_, _| {})
+    }
+
+    // calls `observe` with every obstruction that causes a loop
+    fn run_loop_sim_with(&mut self, mut observe: impl FnMut(&Self, Position)) -> i32 {
+        let mut sum = 0;
+
+        // optimiziation: we only need to attemp to insert an obstruction in
+        // the original path of the guard. All other positionsn will not
+        // result in any changes to the guard's path.
+        self.run_sim();
+        let candidates = self.visited.iter().copied().collect::<Vec<Position>>();
+        for candidate in candidates.iter() {
+            // don't run if guard is there or obstructed
+            if self.states[*candidate] != '.'{
+                continue;
+            }
+
+            // set obstruction
+            self.states[*candidate] = '#';
+
+            // run guard sim
+            let is_loop = self.run_sim();
+
+            // reset
+            self.states[*candidate] = '.';
+            if is_loop {
+                sum += 1;
+                observe(self, *candidate);
+            }
+        }
+        sum
+    }
+
+    // returns true if is loop
+    fn run_sim(&mut self) -> bool {
+        self.run_sim_with(|_, _, _| {})
+    }
+
+    // calls `observe` with the guard's position and direction before every move
+    fn run_sim_with(&mut self, mut observe: impl FnMut(&Self, Position, Direction)) -> bool {
+        let mut position = self.guard;
+        let mut direction = Direction::Up;
+
+        // key is (position, direction)
+        let mut loop_states: HashSet<(Position, Direction)> = HashSet::new();
+        loop {
+            self.visited.insert(position);
+            observe(self, position, direction);
+            let in_loop = !loop_states.insert((position, direction));
+            if in_loop {
+                return true;
+            }
+
+            // potential_position
+            let Some(pp) = self.states.offset(position, direction) else {
+                break;
+            };
+
+            let obstructed = self.states[pp] == '#';
+            if obstructed {
+                direction = direction.turn_right();
+                continue;
+            }
+            
+            position = pp;
+        }
+        false
+    }
+
+    fn count_visited(&self) -> i32 {
+        self.visited.len() as i32
+    }
+
+    // the map with the guard's path as X, and obstructions that would trap
+    // the guard in a loop as O
+    fn frame(&self, path: &HashSet<Position>, guard: Option<(Position, Direction)>, obstructions: &[Position]) -> Frame {
+        let mut states = self.states.clone();
+        let mut set = |position, c| states[position] = c;
+        path.iter().for_each(|&position| set(position, 'X'));
+        obstructions.iter().for_each(|&position| set(position, 'O'));
+        if let Some((position, direction)) = guard {
+            set(position, match direction {
+                Direction::Up => '^',
+                Direction::Right => '>',
+                Direction::Down => 'v',
+                Direction::Left => '<',
+            });
+        }
+        Frame::from(&states)
+    }
+}
+
+impl Visualize for LabSim {
+    fn palette(&self) -> Palette {
+        Palette::new()
+            .with("#", Rgb::GRAY)
+            .with("X", Rgb::BLUE)
+            .with("^>v<", Rgb::RED)
+            .with("O", Rgb::YELLOW)
+            .with(".", Rgb(60, 60, 60))
+    }
+
+    /// Walks the guard's path, then adds the obstructions that cause loops.
+    fn record(&mut self, recorder: &mut Recorder) {
+        let mut last = (self.guard, Direction::Up);
+        self.run_sim_with(|sim, position, direction| {
+            last = (position, direction);
+            recorder.step(|| {
+                let caption = format!("{} positions visited", sim.visited.len());
+                sim.frame(&sim.visited, Some((position, direction)), &[]).with_caption(caption)
+            });
+        });
+        let path = self.visited.clone();
+        let caption = format!("{} positions visited", path.len());
+        recorder.keyframe(self.frame(&path, Some(last), &[]).with_caption(caption));
+
+        let mut obstructions = vec![];
+        self.run_loop_sim_with(|sim, obstruction| {
+            obstructions.push(obstruction);
+            recorder.step(|| {
+                let caption = format!("{} obstructions cause a loop", obstructions.len());
+                sim.frame(&path, None, &obstructions).with_caption(caption)
+            });
+        });
+        let caption = format!("done: {} positions visited, {} obstructions cause a loop", path.len(), obstructions.len());
+        recorder.keyframe(self.frame(&path, None, &obstructions).with_caption(caption));
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn day(&self) -> u32 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn parse(&self, input: &str) -> Option<Box<dyn Any>> {
+        Some(Box::new(LabSim::from_input(input)))
+    }
+
+    fn visualize(&self, input: &str) -> Option<Result<Box<dyn Visualize>, AocError>> {
+        Some(LabSim::from_input(input).map(|sim| Box::new(sim) as Box<dyn Visualize>))
+    }
+}
+
+fn part_1(input: &str) -> Result<i32, AocError> {
+    let mut lab_sim = LabSim::from_input(input)?;
+    lab_sim.run_sim();
+    Ok(lab_sim.count_visited())
+}
+
+fn part_2(input: &str) -> Result<i32, AocError> {
+    let mut lab_sim = LabSim::from_input(input)?;
+    Ok(lab_sim.run_loop_sim())
+}
+
+advent_of_code_2024::examples! {
+    Day6,
+    "example.txt" => { part_1: 41, part_2: 6 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_guard() {
+        assert_eq!(part_1("..\n.#\n").unwrap_err(), AocError::parse(3, 1, "", "a guard '^' somewhere in the map"));
+    }
+}
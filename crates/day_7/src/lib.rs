@@ -0,0 +1,109 @@
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::parse;
+use std::any::Any;
+use std::path::Path;
+
+struct Equation {
+    result: i64,
+    values: Vec<i64>,
+}
+
+impl Equation {
+    fn vec_from_input(input: &str) -> Result<Vec<Self>, AocError> {
+        let equations = parse::key_values(
+            input,
+            ": ",
+            "an equation like '190: 10 19'",
+            |result| parse::value(result, "a number"),
+            |text| match parse::ints(text, "a number")? {
+                values if values.is_empty() => Err(AocError::parse_at(text, text, "at least one number")),
+                values => Ok(values),
+            },
+        )?;
+        Ok(equations.into_iter().map(|(result, values)| Equation { result, values }).collect())
+    }
+
+    fn is_valid_slice(value: i64, slice: &[i64], allow_concat: bool) -> bool {
+        if slice.is_empty() {
+            panic!("slice was empty");
+        }
+        if slice.len() == 1 {
+            return slice[0] == value;
+        }
+
+        // start from the right side of the vec
+        // if we would add (+)
+        let last_index = slice.len()-1;
+        if allow_concat {
+            // find modulus, it should be a power of 10
+            let last = slice[last_index];
+            let modulus = 10_i64.pow(format!("{last}").len() as u32);
+            let remainder = value % modulus;
+            if remainder == last && Self::is_valid_slice(value / modulus, &slice[..last_index], allow_concat) {
+                return true;
+            }
+        }
+        Self::is_valid_slice(value - slice[last_index], &slice[..last_index], allow_concat)
+            || (value % slice[last_index] == 0 && Self::is_valid_slice(value / slice[last_index], &slice[..last_index], allow_concat))
+    }
+
+    fn is_valid(&self, allow_concat: bool) -> bool {
+        Self::is_valid_slice(self.result, &self.values[..], allow_concat)
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn day(&self) -> u32 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Bridge Repair"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn parse(&self, input: &str) -> Option<Box<dyn Any>> {
+        Some(Box::new(Equation::vec_from_input(input)))
+    }
+}
+
+fn part_1(input: &str) -> Result<i64, AocError> {
+    let equations = Equation::vec_from_input(input)?;
+    Ok(equations.into_iter().filter(|eq| eq.is_valid(false)).map(|eq| eq.result).sum::<i64>())
+}
+
+fn part_2(input: &str) -> Result<i64, AocError> {
+    let equations = Equation::vec_from_input(input)?;
+    Ok(equations.into_iter().filter(|eq| eq.is_valid(true)).map(|eq| eq.result).sum::<i64>())
+}
+
+advent_of_code_2024::examples! {
+    Day7,
+    "example.txt" => { part_1: 3749, part_2: 11387 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = Equation::vec_from_input("190: 10 19\n3267 81 40 27\n").err();
+        assert_eq!(error, Some(AocError::parse(2, 1, "3267 81 40 27", "an equation like '190: 10 19'")));
+        let error = Equation::vec_from_input("190: 10 99999999999999999999\n").err();
+        assert_eq!(error, Some(AocError::parse(1, 9, "99999999999999999999", "a number")));
+    }
+}
@@ -0,0 +1,170 @@
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::grid::Grid;
+use advent_of_code_2024::aoc::point::Point;
+use advent_of_code_2024::aoc::visualize::{Frame, Palette, Recorder, Rgb, Visualize};
+use advent_of_code_2024::debug;
+use std::collections::{HashMap, HashSet};
+use std::any::Any;
+use std::path::Path;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn day(&self) -> u32 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Resonant Collinearity"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn parse(&self, input: &str) -> Option<Box<dyn Any>> {
+        Some(Box::new(parse_input(input)))
+    }
+
+    fn visualize(&self, input: &str) -> Option<Result<Box<dyn Visualize>, AocError>> {
+        Some(parse_input(input).map(|(map, antennas)| Box::new(Antinodes { map, antennas }) as Box<dyn Visualize>))
+    }
+}
+
+type Antennas = HashMap<char, Vec<Point>>;
+
+// returns (map, antenna_locations)
+fn parse_input(input: &str) -> Result<(Grid<char>, Antennas), AocError> {
+    let map: Grid<char> = input.parse()?;
+    let mut antennas: Antennas = HashMap::new();
+    for (position, &c) in map.iter() {
+        if c != '.' && c != '#' {
+            let entry = antennas.entry(c).or_insert(vec![]);
+            entry.push(Point::from(position));
+        }
+    }
+    Ok((map, antennas))
+}
+
+fn in_bounds(map: &Grid<char>, node: Point) -> bool {
+    map.checked_position(node).is_some()
+}
+
+// the map with antinodes that are not antennas marked '#'
+fn mark_nodes(map: &Grid<char>, nodes: &HashSet<Point>) -> Grid<char> {
+    let mut map = map.clone();
+    for &node in nodes {
+        if let Some(position) = map.checked_position(node)
+            && map[position] == '.'
+        {
+            map[position] = '#';
+        }
+    }
+    map
+}
+
+fn part_1(input: &str) -> Result<i32, AocError> {
+    let (map, antennas) = parse_input(input)?;
+    let mut set: HashSet<Point> = HashSet::new();
+    for freq in antennas.keys() {
+        let positions: &Vec<Point> = antennas.get(freq).unwrap();
+        for i in 0..positions.len().saturating_sub(1) {
+            for j in i+1..positions.len() {
+                let a = positions[i];
+                let b = positions[j];
+
+                let diff = a - b;
+                let first_node = a + diff;
+                let second_node = b - diff;
+                set.insert(first_node);
+                set.insert(second_node);
+            }
+        }
+    }
+    let nodes = set.into_iter().filter(|&node| in_bounds(&map, node)).collect::<HashSet<Point>>();
+    debug!("antinodes:\n{}", mark_nodes(&map, &nodes));
+
+    Ok(nodes.len() as i32)
+}
+
+fn part_2(input: &str) -> Result<i32, AocError> {
+    let (map, antennas) = parse_input(input)?;
+    let nodes = resonant_antinodes(&map, &antennas, |_| {});
+    debug!("antinodes:\n{}", mark_nodes(&map, &nodes));
+
+    Ok(nodes.len() as i32)
+}
+
+// `observe` sees the antinodes found so far after each pair of antennas
+fn resonant_antinodes(map: &Grid<char>, antennas: &Antennas, mut observe: impl FnMut(&HashSet<Point>)) -> HashSet<Point> {
+    let mut set: HashSet<Point> = HashSet::new();
+    for freq in antennas.keys() {
+        let positions: &Vec<Point> = antennas.get(freq).unwrap();
+        for i in 0..positions.len().saturating_sub(1) {
+            for j in i+1..positions.len() {
+                let a = positions[i];
+                let b = positions[j];
+
+                let diff = a - b;
+                for mult in 0..1000 {
+                    let node = a + diff * mult;
+                    if !in_bounds(map, node) {
+                        break;
+                    }
+                    set.insert(node);
+                }
+                for mult in 0..1000 {
+                    let node = a - diff * mult;
+                    if !in_bounds(map, node) {
+                        break;
+                    }
+                    set.insert(node);
+                }
+                let second_node = b - diff;
+                set.insert(second_node);
+                observe(&set);
+            }
+        }
+    }
+    set.into_iter().filter(|&node| in_bounds(map, node)).collect::<HashSet<Point>>()
+}
+
+/// Replays part 2, marking antinodes that are not antennas with `#`.
+struct Antinodes {
+    map: Grid<char>,
+    antennas: Antennas,
+}
+
+impl Antinodes {
+    fn frame(&self, nodes: &HashSet<Point>) -> Frame {
+        Frame::from(&mark_nodes(&self.map, nodes))
+    }
+}
+
+impl Visualize for Antinodes {
+    fn palette(&self) -> Palette {
+        Palette::new().with("#", Rgb::YELLOW).with(".", Rgb(60, 60, 60)).with_default(Rgb::BLUE)
+    }
+
+    fn record(&mut self, recorder: &mut Recorder) {
+        let mut pairs = 0;
+        let nodes = resonant_antinodes(&self.map, &self.antennas, |nodes| {
+            pairs += 1;
+            recorder.step(|| self.frame(nodes).with_caption(format!("{pairs} antenna pairs")));
+        });
+        recorder.keyframe(self.frame(&nodes).with_caption(format!("done: {} antinodes", nodes.len())));
+    }
+}
+
+advent_of_code_2024::examples! {
+    Day8,
+    "example.txt" => { part_1: 14, part_2: 34 },
+}
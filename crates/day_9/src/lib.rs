@@ -0,0 +1,117 @@
+mod mem;
+
+use advent_of_code_2024::aoc::{AocError, Answer, Solution};
+use advent_of_code_2024::aoc::visualize::Visualize;
+use advent_of_code_2024::debug;
+use std::path::Path;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn day(&self) -> u32 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Disk Fragmenter"
+    }
+
+    fn input_path(&self) -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn visualize(&self, input: &str) -> Option<Result<Box<dyn Visualize>, AocError>> {
+        Some(BlockMemory::parse(input.trim_end()).map(|memory| Box::new(memory) as Box<dyn Visualize>))
+    }
+}
+
+fn format_blocks(blocks: &[Option<i64>]) -> String {
+    blocks.iter().map(|&item| item.map(|x| (x % 10) as u8 + b'0').unwrap_or(b'.') as char).collect()
+}
+
+fn checksum(blocks: &[Option<i64>]) -> i64 {
+    let mut sum = 0;
+    for (i, item) in blocks.iter().enumerate() {
+        if let Some(file_id) = item {
+            sum += *file_id * i as i64;
+        }
+    }
+    sum
+}
+
+fn part_1(input: &str) -> Result<i64, AocError> {
+    let memory = BlockMemory::parse(input.trim_end())?;
+    let mut blocks: Vec<Option<i64>> = memory.blocks().iter().map(|block| block.map(i64::from)).collect();
+
+    let mut front = 0;
+    let mut back = blocks.len()-1;
+
+    debug!("before compacting: {}", format_blocks(&blocks));
+
+    loop {
+        if front == back {
+            break;
+        }
+
+        if blocks[front].is_some() {
+            front += 1;
+            continue;
+        }
+
+        if blocks[back].is_none() {
+            back -= 1;
+            continue;
+        }
+
+        (blocks[front], blocks[back]) = (blocks[back], None);
+    }
+
+    debug!("after compacting: {}", format_blocks(&blocks));
+
+    Ok(checksum(&blocks))
+}
+
+use mem::*;
+
+fn part_2(input: &str) -> Result<i64, AocError> {
+    let mut memory = BlockMemory::parse(input.trim_end())?;
+    memory.compress();
+    Ok(memory.checksum())
+}
+
+advent_of_code_2024::examples! {
+    Day9,
+    "example.txt" => { part_1: 1928, part_2: 2858 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small() {
+        // 0..111....22222 compacts to 022111222 for part 1, and not at all
+        // for part 2
+        assert_eq!(part_1("12345"), Ok(60));
+        assert_eq!(part_2("12345\n"), Ok(132));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = AocError::parse(1, 1, "", "a disk map ending with a file");
+        assert_eq!(part_1(""), Err(error.clone()));
+        assert_eq!(part_2(""), Err(error));
+        let error = AocError::parse(1, 2, "2", "a disk map ending with a file");
+        assert_eq!(part_1("12"), Err(error.clone()));
+        assert_eq!(part_2("12"), Err(error));
+        assert_eq!(part_1("1x"), Err(AocError::parse(1, 2, "x", "a digit")));
+    }
+}
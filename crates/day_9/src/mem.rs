@@ -1,3 +1,9 @@
+use std::fmt;
+
+use advent_of_code_2024::aoc::AocError;
+use advent_of_code_2024::aoc::visualize::{Frame, Palette, Recorder, Rgb, Visualize};
+use advent_of_code_2024::trace;
+
 type FileID = i32;
 
 pub struct BlockMemory {
@@ -5,9 +11,13 @@ pub struct BlockMemory {
 }
 
 impl BlockMemory {
-    pub fn parse(input: &str) -> Self {
+    /// Reads a disk map, which must end with a file so that compacting has
+    /// something to move.
+    pub fn parse(input: &str) -> Result<Self, AocError> {
         let mut blocks = vec![];
-        for (i, c) in input.chars().enumerate() {
+        let mut last = 0;
+        for (i, (offset, c)) in input.char_indices().enumerate() {
+            last = offset;
             let is_file = i % 2 == 0;
             let value = if is_file {
                 let file_id: FileID = i as FileID / 2;
@@ -16,30 +26,22 @@ impl BlockMemory {
                 None
             };
 
-            let length = c.to_digit(10).expect(format!("failed to parse digit from '{c}'").as_str()); // radix = 10
-            for i in 0..length {
+            let length = c.to_digit(10) // radix = 10
+                .ok_or_else(|| AocError::parse_at(input, &input[offset..offset + c.len_utf8()], "a digit"))?;
+            for _ in 0..length {
                 blocks.push(value);
             }
         }
-        Self {
-            blocks,
-        }
-    }
-
-    fn to_string(&self) -> String {
-        let mut s = String::new();
-        for item in self.blocks.iter() {
-            let c = match item {
-                Some(file_id) => ('0' as u8 + *file_id as u8) as char,
-                None => '.',
-            };
-            s.push(c);
+        if !matches!(blocks.last(), Some(Some(_))) {
+            return Err(AocError::parse_at(input, &input[last..], "a disk map ending with a file"));
         }
-        s
+        Ok(Self {
+            blocks,
+        })
     }
 
-    fn print(&self) {
-        println!("{}", self.to_string());
+    pub fn blocks(&self) -> &[Option<FileID>] {
+        &self.blocks
     }
 
     fn len(&self) -> usize {
@@ -47,26 +49,29 @@ impl BlockMemory {
     }
 
     pub fn compress(&mut self) {
+        self.compress_with(|_| {});
+    }
+
+    // calls `observe` after every file that is moved
+    fn compress_with(&mut self, mut observe: impl FnMut(&Self)) {
         let mut file_scanner = FileScanner::new(self);
 
         // loop over files
         let largest_file_id = self.blocks[self.blocks.len()-1].unwrap();
         'file:  for file_id in (0..=largest_file_id).rev() {
             let Some(file) = file_scanner.next(self, file_id) else {
-                println!("breaking!");
+                trace!("no file {file_id} left to move");
                 break;
             };
             // file 0 never needs to be moved
             if file.0 == 0 {
                 break;
             }
-            println!("attempting to swap file {}", file.0);
+            trace!("attempting to swap file {}", file.0);
             let mut freespace_scanner = FreeSpaceScanner::new(self);
 
             // loop over freespace
-            'freespace: loop {
-                let Some(freespace) = freespace_scanner.next(self) else { break; };
-
+            while let Some(freespace) = freespace_scanner.next(self) {
                 let file_len = file.1.1 - file.1.0;
                 let freespace_len = freespace.1 - freespace.0;
 
@@ -81,7 +86,7 @@ impl BlockMemory {
 
                 // perform swap
                 let file_id = file.0;
-                println!("performing swap on file {file_id}: {} {}", freespace.0, file_len);
+                trace!("performing swap on file {file_id}: {} {}", freespace.0, file_len);
                 for i in 0..file_len {
                     self.blocks[freespace.0 + i] = Some(file_id);
                 }
@@ -89,6 +94,7 @@ impl BlockMemory {
                     self.blocks[i] = None;
                 }
 
+                observe(self);
                 continue 'file; // we swapped the file! go to the outer loop
             }
         }
@@ -103,6 +109,49 @@ impl BlockMemory {
     }
 }
 
+const FRAME_WIDTH: usize = 64;
+
+impl BlockMemory {
+    fn frame(&self) -> Frame {
+        let cells = self.blocks.iter().map(|block| match block {
+            Some(file_id) => char::from_digit((file_id % 10) as u32, 10).unwrap(),
+            None => '.',
+        });
+        Frame::wrapped(cells, FRAME_WIDTH)
+    }
+}
+
+impl Visualize for BlockMemory {
+    fn palette(&self) -> Palette {
+        (0..10).fold(Palette::new().with(".", Rgb(60, 60, 60)), |palette, digit| {
+            palette.with(&digit.to_string(), Rgb::hue(digit, 10))
+        })
+    }
+
+    fn record(&mut self, recorder: &mut Recorder) {
+        recorder.keyframe(self.frame().with_caption("before compacting"));
+        let mut moved = 0;
+        self.compress_with(|memory| {
+            moved += 1;
+            recorder.step(|| memory.frame().with_caption(format!("{moved} files moved")));
+        });
+        recorder.keyframe(self.frame().with_caption(format!("done: {moved} files moved, checksum {}", self.checksum())));
+    }
+}
+
+impl fmt::Display for BlockMemory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in self.blocks.iter() {
+            let c = match item {
+                Some(file_id) => (b'0' + *file_id as u8) as char,
+                None => '.',
+            };
+            write!(f, "{c}")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 enum ScannerValue {
     File(FileID),
@@ -162,10 +211,6 @@ impl Scanner {
         self.marker = self.pointer;
     }
 
-    fn diff(&mut self) -> usize {
-        (self.marker as i32 - self.pointer as i32).abs() as usize
-    }
-
     fn read(&self, memory: &BlockMemory) -> Option<FileID> {
         memory.blocks[self.pointer]
     }
@@ -177,7 +222,7 @@ struct FreeSpaceScanner {
 }
 
 impl FreeSpaceScanner {
-    fn new(memory: & BlockMemory) -> Self {
+    fn new(_memory: & BlockMemory) -> Self {
         Self { scanner: Scanner::start() }
     }
 
@@ -193,12 +238,7 @@ impl FreeSpaceScanner {
         self.scanner.mark();
 
         // move past freespace
-        loop {
-            match self.scanner.inc(memory) {
-                ScannerValue::Free => continue,
-                _ => break,
-            };
-        }
+        while let ScannerValue::Free = self.scanner.inc(memory) {}
 
         Some((self.scanner.marker, self.scanner.pointer))
     }
@@ -223,7 +263,8 @@ impl FileScanner {
             };
         };
 
-        dbg!(self.scanner.mark(), self.scanner.marker);
+        self.scanner.mark();
+        trace!("found file {curr_file_id} ending at {}", self.scanner.marker);
 
         // move to start of file
         loop {
@@ -242,14 +283,9 @@ impl FileScanner {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_add() {
-        assert_eq!(1 + 1, 2);
-    }
-
     #[test]
     fn test_parse() {
-        let memory = BlockMemory::parse("12345");
+        let memory = BlockMemory::parse("12345").unwrap();
         assert_eq!(memory.blocks, vec![
             Some(0),
             None,
@@ -271,7 +307,7 @@ mod tests {
 
     #[test]
     fn test_free_space_scanner() {
-        let memory = BlockMemory::parse("12345");
+        let memory = BlockMemory::parse("12345").unwrap();
         let mut free_space_scanner = FreeSpaceScanner::new(&memory);
         assert_eq!(free_space_scanner.next(&memory), Some((1, 3)));
         assert_eq!(free_space_scanner.next(&memory), Some((6, 10)));
@@ -281,7 +317,7 @@ mod tests {
 
     #[test]
     fn test_file_scanner() {
-        let memory = BlockMemory::parse("12345");
+        let memory = BlockMemory::parse("12345").unwrap();
         let mut file_scanner = FileScanner::new(&memory);
         assert_eq!(file_scanner.next(&memory, 2), Some((2, (10, 15))));
         assert_eq!(file_scanner.next(&memory, 1), Some((1, (3, 6))));
@@ -292,18 +328,23 @@ mod tests {
 
     #[test]
     fn test_checksum() {
-        let memory = BlockMemory::parse("12345");
-        assert_eq!(memory.checksum(), 1 * (3 + 4 + 5) + 2 * (10 + 11 + 12 + 13 + 14));
+        let memory = BlockMemory::parse("12345").unwrap();
+        assert_eq!(memory.checksum(), (3 + 4 + 5) + 2 * (10 + 11 + 12 + 13 + 14));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(BlockMemory::parse("12a45").err(), Some(AocError::parse(1, 3, "a", "a digit")));
+        assert_eq!(BlockMemory::parse("").err(), Some(AocError::parse(1, 1, "", "a disk map ending with a file")));
+        assert_eq!(BlockMemory::parse("12").err(), Some(AocError::parse(1, 2, "2", "a disk map ending with a file")));
+        assert_eq!(BlockMemory::parse("120").err(), Some(AocError::parse(1, 3, "0", "a disk map ending with a file")));
     }
 
     #[test]
     fn test_example() {
-        let mut memory = BlockMemory::parse("2333133121414131402");
+        let mut memory = BlockMemory::parse("2333133121414131402").unwrap();
         assert_eq!(memory.to_string(), "00...111...2...333.44.5555.6666.777.888899");
-
-        memory.print();
-
-        dbg!(memory.to_string().len());
+        assert_eq!(memory.len(), 42);
 
         let mut file_scanner = FileScanner::new(&memory);
         assert_eq!(file_scanner.next(&memory, 9), Some((9, (40, 42))));
@@ -0,0 +1,616 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use advent_of_code_2024::aoc::Part;
+use advent_of_code_2024::aoc::bench::{self, BenchConfig};
+use advent_of_code_2024::aoc::input::InputSource;
+use advent_of_code_2024::aoc::log::Level;
+use advent_of_code_2024::aoc::report::Format;
+use advent_of_code_2024::aoc::visualize::Palette;
+
+pub const USAGE: &str = "usage: aoc [-v|-vv|-vvv] <command> [options]
+
+commands:
+    list                                      list every implemented day
+    run --day N [--part P] [input options]    run a single day
+    run --all [--input-dir DIR]               run every implemented day in parallel
+        [--jobs N] [--budget TIME]            on N threads, failing if the total wall
+                                              time exceeds TIME (e.g. 1s or 500ms)
+        [--format json|csv|text]              print results as JSON, CSV or text
+        [--timeout TIME]                      give up on any part running over TIME
+                                              and report it as TIMEOUT
+    bench --day N [--part P] [bench options]  time a single day
+    bench --all [bench options]               time every implemented day
+    verify [--day N] [--answers FILE]         check answers against answers.txt
+    fetch --day N | --all                     download and cache puzzle inputs
+    submit --day N --part P [--answer A]      submit an answer; without --answer the
+                                              day is solved first, using input options
+    new-day N [--title T]                     create crates/day_N and register it
+    watch --day N [--interval TIME]           rebuild and re-run a day whenever its
+                                              sources or input change, polling every
+                                              TIME (default 500ms); takes input options
+    animate --day N [animate options]         replay a day's simulation in the terminal,
+                                              or save it as images
+    svg --day N [--out FILE] [--cell N]       draw a day's answer as SVG, to FILE or
+                                              stdout, with N pixels per cell; takes
+                                              input options. Days 10 and 12 can be drawn
+
+input options:
+    --input FILE        read the puzzle input from FILE, or stdin for '-'
+    --input-dir DIR     read DIR/day_N.txt
+By default inputs come from $AOC_INPUT_DIR if set, otherwise the file
+bundled with each day crate.
+
+bench options:
+    --warmup N          untimed runs before measuring (default 3)
+    --iterations N      timed runs (default 10)
+    --compare           compare medians with the last run at another git
+                        revision, failing if any step got slower
+    --baseline REV      compare with the run at REV instead
+    --threshold PCT     how much slower counts as a regression (default 10)
+    plus the input options. Parsing is timed on its own for days that
+    support it; part timings include parsing. Results are kept per git
+    revision in .aoc/bench_history.tsv.
+
+fetch and submit read the session cookie from $AOC_SESSION. Inputs are
+cached in .aoc/inputs, which can be passed to --input-dir. Submissions are
+recorded in .aoc/ledger.tsv, and answers it already rules out are not sent.
+
+animate options:
+    --fps N             frames per second (default 10)
+    --every N           keep one frame in every N simulation steps (default 1)
+    --limit N           keep at most N frames (default 1000)
+    --png DIR           save every frame as DIR/frame_NNNN.png instead of playing
+    --gif FILE          save the frames as an animated GIF instead of playing
+    --cell N            pixels per cell in saved images (default 8)
+    --colors SPEC       recolor cells, as CELLS=RRGGBB pairs separated by commas,
+                        e.g. '#=808080,^>v<=ff0000'
+    plus the input options. Days 6, 8, 9 and 12 can be animated. While
+    playing, space pauses, n and p step, + and - change speed, r restarts and
+    q quits.
+
+-v, -vv and -vvv log info, debug or trace messages from solutions to
+stderr; $AOC_LOG=LEVEL does the same. Nothing is logged by default.
+
+A part that times out cannot be stopped, so it keeps running in the
+background until aoc exits, and may slow down the parts after it.
+
+Build with --features count-allocations to also report heap allocations,
+bytes allocated and peak live bytes for each part.";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Help,
+    List,
+    Run {
+        day: u32,
+        part: Option<Part>,
+        input: InputSource,
+        format: Format,
+        /// How long each part may run; `None` means no limit.
+        timeout: Option<Duration>,
+    },
+    RunAll {
+        input: InputSource,
+        format: Format,
+        /// Worker threads; `None` means one per CPU.
+        jobs: Option<usize>,
+        budget: Option<Duration>,
+        timeout: Option<Duration>,
+    },
+    /// Benchmark days; `None` means every implemented day.
+    Bench {
+        day: Option<u32>,
+        part: Option<Part>,
+        config: BenchConfig,
+        input: InputSource,
+        compare: Option<Compare>,
+    },
+    /// Check answers; `None` means every implemented day.
+    Verify {
+        day: Option<u32>,
+        answers: Option<PathBuf>,
+    },
+    /// Download inputs; `None` means every implemented day.
+    Fetch {
+        day: Option<u32>,
+    },
+    Submit {
+        day: u32,
+        part: Part,
+        answer: Option<String>,
+        input: InputSource,
+    },
+    NewDay {
+        day: u32,
+        title: Option<String>,
+    },
+    Watch {
+        day: u32,
+        input: InputSource,
+        interval: Duration,
+    },
+    Animate {
+        day: u32,
+        input: InputSource,
+        fps: u32,
+        every: u32,
+        limit: u32,
+        /// Where to save images; `None` means play in the terminal.
+        export: Option<Export>,
+        cell_size: u32,
+        colors: Palette,
+    },
+    Svg {
+        day: u32,
+        input: InputSource,
+        /// Where to write the drawing; `None` means stdout.
+        out: Option<PathBuf>,
+        /// Pixels per cell; `None` leaves it to the day.
+        cell_size: Option<u32>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Export {
+    /// A directory of numbered PNG files.
+    Png(PathBuf),
+    Gif(PathBuf),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Compare {
+    /// Revision to compare with; `None` means the latest other revision.
+    pub baseline: Option<String>,
+    pub threshold_percent: u32,
+}
+
+/// The arguments following the command name. Flags are consumed as they are
+/// read, so anything left over at the end was not understood.
+struct Args<'a> {
+    args: Vec<&'a str>,
+}
+
+impl<'a> Args<'a> {
+    fn new(args: &'a [String]) -> Self {
+        Self { args: args.iter().map(String::as_str).collect() }
+    }
+
+    fn flag(&mut self, name: &str) -> bool {
+        match self.args.iter().position(|arg| *arg == name) {
+            Some(i) => {
+                self.args.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn value(&mut self, name: &str) -> Result<Option<&'a str>, String> {
+        let Some(i) = self.args.iter().position(|arg| *arg == name) else {
+            return Ok(None);
+        };
+        if i + 1 >= self.args.len() {
+            return Err(format!("{name} needs a value"));
+        }
+        let value = self.args.remove(i + 1);
+        self.args.remove(i);
+        Ok(Some(value))
+    }
+
+    fn number(&mut self, name: &str) -> Result<Option<u32>, String> {
+        self.value(name)?
+            .map(|value| value.parse::<u32>().map_err(|_| format!("{name} expects a number, got '{value}'")))
+            .transpose()
+    }
+
+    /// Takes the first remaining argument if it is not a flag.
+    fn positional(&mut self) -> Option<&'a str> {
+        match self.args.first() {
+            Some(arg) if !arg.starts_with('-') => Some(self.args.remove(0)),
+            _ => None,
+        }
+    }
+
+    fn finish(self) -> Result<(), String> {
+        match self.args.first() {
+            Some(arg) => Err(format!("unexpected argument '{arg}'")),
+            None => Ok(()),
+        }
+    }
+}
+
+fn part(args: &mut Args) -> Result<Option<Part>, String> {
+    args.number("--part")?
+        .map(|part| Part::from_number(part).ok_or(format!("there is no part {part}")))
+        .transpose()
+}
+
+/// Parses durations such as `1s`, `1.5s` or `500ms`.
+fn duration(name: &str, value: &str) -> Result<Duration, String> {
+    bench::parse_duration(value).ok_or_else(|| format!("{name} expects a duration like 1s or 500ms, got '{value}'"))
+}
+
+fn input_source(args: &mut Args) -> Result<InputSource, String> {
+    match (args.value("--input")?, args.value("--input-dir")?) {
+        (Some(_), Some(_)) => Err("--input and --input-dir cannot be combined".to_string()),
+        (Some(file), None) => Ok(InputSource::from_arg(file)),
+        (None, Some(dir)) => Ok(InputSource::Dir(PathBuf::from(dir))),
+        (None, None) => Ok(InputSource::Default),
+    }
+}
+
+/// Takes out every `-v`, `-vv` or `-vvv`, returning the log level they add up
+/// to along with the remaining arguments.
+pub fn verbosity(args: &[String]) -> (Option<Level>, Vec<String>) {
+    let is_verbose = |arg: &String| matches!(arg.as_str(), "-v" | "-vv" | "-vvv");
+    let count = args.iter().filter(|arg| is_verbose(arg)).map(|arg| arg.len() - 1).sum();
+    let rest = args.iter().filter(|arg| !is_verbose(arg)).cloned().collect();
+    (Level::from_verbosity(count), rest)
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    let Some((name, rest)) = args.split_first() else {
+        return Ok(Command::Help);
+    };
+    let mut args = Args::new(rest);
+
+    let command = match name.as_str() {
+        "help" | "--help" | "-h" => Command::Help,
+        "list" => Command::List,
+        "run" => {
+            let all = args.flag("--all");
+            let day = args.number("--day")?;
+            let part = part(&mut args)?;
+            let input = input_source(&mut args)?;
+            let format = args.value("--format")?.map(str::parse).transpose()?.unwrap_or_default();
+            let jobs = args.number("--jobs")?.map(|jobs| jobs.max(1) as usize);
+            let budget = args.value("--budget")?.map(|budget| duration("--budget", budget)).transpose()?;
+            let timeout = args.value("--timeout")?.map(|timeout| duration("--timeout", timeout)).transpose()?;
+            match (all, day) {
+                (true, None) if part.is_none() && !matches!(input, InputSource::File(_) | InputSource::Stdin) => {
+                    Command::RunAll { input, format, jobs, budget, timeout }
+                }
+                (true, _) => return Err("--all cannot be combined with --day, --part or --input".to_string()),
+                (false, Some(_)) if jobs.is_some() || budget.is_some() => {
+                    return Err("--jobs and --budget only apply to --all".to_string());
+                }
+                (false, Some(day)) => Command::Run { day, part, input, format, timeout },
+                (false, None) => return Err("run needs either --day N or --all".to_string()),
+            }
+        }
+        "bench" => {
+            let all = args.flag("--all");
+            let day = args.number("--day")?;
+            let part = part(&mut args)?;
+            let defaults = BenchConfig::default();
+            let config = BenchConfig {
+                warmup: args.number("--warmup")?.unwrap_or(defaults.warmup),
+                iterations: args.number("--iterations")?.unwrap_or(defaults.iterations),
+            };
+            if config.iterations == 0 {
+                return Err("--iterations must be at least 1".to_string());
+            }
+            let input = input_source(&mut args)?;
+            let compare = match (args.flag("--compare"), args.value("--baseline")?, args.number("--threshold")?) {
+                (true, baseline, threshold) => Some(Compare {
+                    baseline: baseline.map(String::from),
+                    threshold_percent: threshold.unwrap_or(10),
+                }),
+                (false, None, None) => None,
+                (false, _, _) => return Err("--baseline and --threshold need --compare".to_string()),
+            };
+            match (all, day) {
+                (true, None) if part.is_none() && !matches!(input, InputSource::File(_) | InputSource::Stdin) => {
+                    Command::Bench { day: None, part, config, input, compare }
+                }
+                (true, _) => return Err("--all cannot be combined with --day, --part or --input".to_string()),
+                (false, Some(day)) => Command::Bench { day: Some(day), part, config, input, compare },
+                (false, None) => return Err("bench needs either --day N or --all".to_string()),
+            }
+        }
+        "verify" => Command::Verify {
+            day: args.number("--day")?,
+            answers: args.value("--answers")?.map(PathBuf::from),
+        },
+        "fetch" => match (args.flag("--all"), args.number("--day")?) {
+            (true, None) => Command::Fetch { day: None },
+            (false, Some(day)) => Command::Fetch { day: Some(day) },
+            _ => return Err("fetch needs either --day N or --all".to_string()),
+        },
+        "submit" => {
+            let day = args.number("--day")?.ok_or("submit needs --day N")?;
+            let part = part(&mut args)?.ok_or("submit needs --part P")?;
+            let answer = args.value("--answer")?.map(String::from);
+            let input = input_source(&mut args)?;
+            if answer.is_some() && input != InputSource::Default {
+                return Err("--answer cannot be combined with input options".to_string());
+            }
+            Command::Submit { day, part, answer, input }
+        }
+        "new-day" => {
+            let title = args.value("--title")?.map(String::from);
+            let day = args.positional().ok_or("new-day needs a day number")?;
+            let day = day.parse::<u32>().map_err(|_| format!("new-day expects a day number, got '{day}'"))?;
+            Command::NewDay { day, title }
+        }
+        "watch" => {
+            let day = args.number("--day")?.ok_or("watch needs --day N")?;
+            let input = input_source(&mut args)?;
+            if input == InputSource::Stdin {
+                return Err("watch needs an input file, not stdin".to_string());
+            }
+            let interval = args.value("--interval")?.map(|interval| duration("--interval", interval)).transpose()?;
+            Command::Watch { day, input, interval: interval.unwrap_or(Duration::from_millis(500)) }
+        }
+        "animate" => {
+            let day = args.number("--day")?.ok_or("animate needs --day N")?;
+            let input = input_source(&mut args)?;
+            let fps = args.number("--fps")?.unwrap_or(10);
+            let every = args.number("--every")?.unwrap_or(1);
+            let limit = args.number("--limit")?.unwrap_or(1000);
+            let export = match (args.value("--png")?, args.value("--gif")?) {
+                (Some(_), Some(_)) => return Err("--png and --gif cannot be combined".to_string()),
+                (Some(dir), None) => Some(Export::Png(PathBuf::from(dir))),
+                (None, Some(file)) => Some(Export::Gif(PathBuf::from(file))),
+                (None, None) => None,
+            };
+            let cell_size = args.number("--cell")?.unwrap_or(8);
+            let colors = args.value("--colors")?.map(|colors| colors.parse().map_err(|e| format!("--colors: {e}"))).transpose()?;
+            if fps == 0 || every == 0 || cell_size == 0 {
+                return Err("--fps, --every and --cell must be at least 1".to_string());
+            }
+            Command::Animate { day, input, fps, every, limit, export, cell_size, colors: colors.unwrap_or_default() }
+        }
+        "svg" => {
+            let day = args.number("--day")?.ok_or("svg needs --day N")?;
+            let input = input_source(&mut args)?;
+            let out = args.value("--out")?.map(PathBuf::from);
+            let cell_size = args.number("--cell")?;
+            if cell_size == Some(0) {
+                return Err("--cell must be at least 1".to_string());
+            }
+            Command::Svg { day, input, out, cell_size }
+        }
+        _ => return Err(format!("unknown command '{name}'")),
+    };
+
+    args.finish()?;
+    Ok(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code_2024::aoc::visualize::Rgb;
+
+    fn parse_str(args: &str) -> Result<Command, String> {
+        parse(&args.split_whitespace().map(String::from).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_parse_run() {
+        let text = Format::Text;
+        assert_eq!(
+            parse_str("run --day 6"),
+            Ok(Command::Run { day: 6, part: None, input: InputSource::Default, format: text, timeout: None }),
+        );
+        assert_eq!(
+            parse_str("run --part 2 --input path/to/file --day 6 --timeout 30s"),
+            Ok(Command::Run {
+                day: 6,
+                part: Some(Part::Two),
+                input: InputSource::File(PathBuf::from("path/to/file")),
+                format: text,
+                timeout: Some(Duration::from_secs(30)),
+            }),
+        );
+        assert_eq!(
+            parse_str("run --day 6 --input -"),
+            Ok(Command::Run { day: 6, part: None, input: InputSource::Stdin, format: text, timeout: None }),
+        );
+        assert_eq!(
+            parse_str("run --all"),
+            Ok(Command::RunAll { input: InputSource::Default, format: text, jobs: None, budget: None, timeout: None }),
+        );
+        assert_eq!(
+            parse_str("run --all --input-dir inputs"),
+            Ok(Command::RunAll {
+                input: InputSource::Dir(PathBuf::from("inputs")),
+                format: text,
+                jobs: None,
+                budget: None,
+                timeout: None,
+            }),
+        );
+        assert_eq!(
+            parse_str("run --all --format json --jobs 4 --budget 1.5s --timeout 500ms"),
+            Ok(Command::RunAll {
+                input: InputSource::Default,
+                format: Format::Json,
+                jobs: Some(4),
+                budget: Some(Duration::from_millis(1500)),
+                timeout: Some(Duration::from_millis(500)),
+            }),
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_str("run").is_err());
+        assert!(parse_str("run --day").is_err());
+        assert!(parse_str("run --day six").is_err());
+        assert!(parse_str("run --day 6 --part 3").is_err());
+        assert!(parse_str("run --all --day 6").is_err());
+        assert!(parse_str("run --all --input file.txt").is_err());
+        assert!(parse_str("run --day 6 --input a.txt --input-dir inputs").is_err());
+        assert!(parse_str("run --day 6 --format xml").is_err());
+        assert!(parse_str("run --day 6 --jobs 2").is_err());
+        assert!(parse_str("run --all --budget soon").is_err());
+        assert!(parse_str("run --day 6 --timeout 10").is_err());
+        assert!(parse_str("list --verbose").is_err());
+        assert!(parse_str("frobnicate").is_err());
+    }
+
+    #[test]
+    fn test_duration() {
+        assert_eq!(duration("--budget", "1s"), Ok(Duration::from_secs(1)));
+        assert_eq!(duration("--budget", "0.25s"), Ok(Duration::from_millis(250)));
+        assert_eq!(duration("--budget", "500ms"), Ok(Duration::from_millis(500)));
+        assert!(duration("--budget", "500").is_err());
+        assert!(duration("--budget", "-1s").is_err());
+    }
+
+    #[test]
+    fn test_verbosity() {
+        let args = |args: &str| args.split_whitespace().map(String::from).collect::<Vec<_>>();
+        assert_eq!(verbosity(&args("run --day 9")), (None, args("run --day 9")));
+        assert_eq!(verbosity(&args("-v run --day 9")), (Some(Level::Info), args("run --day 9")));
+        assert_eq!(verbosity(&args("-v run -vv --day 9")), (Some(Level::Trace), args("run --day 9")));
+    }
+
+    #[test]
+    fn test_parse_help() {
+        assert_eq!(parse_str(""), Ok(Command::Help));
+        assert_eq!(parse_str("list"), Ok(Command::List));
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse_str("bench --day 7 --part 2 --iterations 50"),
+            Ok(Command::Bench {
+                day: Some(7),
+                part: Some(Part::Two),
+                config: BenchConfig { warmup: 3, iterations: 50 },
+                input: InputSource::Default,
+                compare: None,
+            }),
+        );
+        assert_eq!(
+            parse_str("bench --all --warmup 0 --compare"),
+            Ok(Command::Bench {
+                day: None,
+                part: None,
+                config: BenchConfig { warmup: 0, iterations: 10 },
+                input: InputSource::Default,
+                compare: Some(Compare { baseline: None, threshold_percent: 10 }),
+            }),
+        );
+        assert!(matches!(
+            parse_str("bench --day 6 --compare --baseline abc123 --threshold 5"),
+            Ok(Command::Bench { compare: Some(Compare { baseline: Some(baseline), threshold_percent: 5 }), .. }) if baseline == "abc123",
+        ));
+        assert!(parse_str("bench --day 6 --threshold 5").is_err());
+        assert!(parse_str("bench").is_err());
+        assert!(parse_str("bench --day 7 --iterations 0").is_err());
+        assert!(parse_str("bench --all --part 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(parse_str("verify"), Ok(Command::Verify { day: None, answers: None }));
+        assert_eq!(
+            parse_str("verify --day 9 --answers mine.txt"),
+            Ok(Command::Verify { day: Some(9), answers: Some(PathBuf::from("mine.txt")) }),
+        );
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        assert_eq!(parse_str("fetch --day 3"), Ok(Command::Fetch { day: Some(3) }));
+        assert_eq!(parse_str("fetch --all"), Ok(Command::Fetch { day: None }));
+        assert!(parse_str("fetch").is_err());
+        assert!(parse_str("fetch --all --day 3").is_err());
+    }
+
+    #[test]
+    fn test_parse_new_day() {
+        assert_eq!(parse_str("new-day 13"), Ok(Command::NewDay { day: 13, title: None }));
+        assert_eq!(
+            parse_str("new-day --title Claw 13"),
+            Ok(Command::NewDay { day: 13, title: Some("Claw".to_string()) }),
+        );
+        assert!(parse_str("new-day").is_err());
+        assert!(parse_str("new-day thirteen").is_err());
+        assert!(parse_str("new-day 13 14").is_err());
+    }
+
+    #[test]
+    fn test_parse_animate() {
+        assert_eq!(
+            parse_str("animate --day 6"),
+            Ok(Command::Animate {
+                day: 6,
+                input: InputSource::Default,
+                fps: 10,
+                every: 1,
+                limit: 1000,
+                export: None,
+                cell_size: 8,
+                colors: Palette::new(),
+            }),
+        );
+        assert_eq!(
+            parse_str("animate --day 9 --fps 30 --every 5 --limit 50 --input - --gif out.gif --cell 4 --colors .=000000"),
+            Ok(Command::Animate {
+                day: 9,
+                input: InputSource::Stdin,
+                fps: 30,
+                every: 5,
+                limit: 50,
+                export: Some(Export::Gif("out.gif".into())),
+                cell_size: 4,
+                colors: Palette::new().with(".", Rgb::BLACK),
+            }),
+        );
+        assert!(matches!(parse_str("animate --day 12 --png frames"), Ok(Command::Animate { export: Some(Export::Png(_)), .. })));
+        assert!(parse_str("animate --day 6 --png frames --gif out.gif").is_err());
+        assert!(parse_str("animate --day 6 --colors red").is_err());
+        assert!(parse_str("animate --day 6 --every 0").is_err());
+        assert!(parse_str("animate").is_err());
+    }
+
+    #[test]
+    fn test_parse_svg() {
+        assert_eq!(
+            parse_str("svg --day 12 --out regions.svg --cell 20"),
+            Ok(Command::Svg { day: 12, input: InputSource::Default, out: Some(PathBuf::from("regions.svg")), cell_size: Some(20) }),
+        );
+        assert_eq!(
+            parse_str("svg --day 10 --input -"),
+            Ok(Command::Svg { day: 10, input: InputSource::Stdin, out: None, cell_size: None }),
+        );
+        assert!(parse_str("svg --day 10 --cell 0").is_err());
+        assert!(parse_str("svg").is_err());
+    }
+
+    #[test]
+    fn test_parse_watch() {
+        assert_eq!(
+            parse_str("watch --day 9"),
+            Ok(Command::Watch { day: 9, input: InputSource::Default, interval: Duration::from_millis(500) }),
+        );
+        assert_eq!(
+            parse_str("watch --day 9 --interval 2s --input example.txt"),
+            Ok(Command::Watch { day: 9, input: InputSource::File("example.txt".into()), interval: Duration::from_secs(2) }),
+        );
+        assert!(parse_str("watch").is_err());
+        assert!(parse_str("watch --day 9 --input -").is_err());
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        assert_eq!(
+            parse_str("submit --day 3 --part 1 --answer 42"),
+            Ok(Command::Submit { day: 3, part: Part::One, answer: Some("42".to_string()), input: InputSource::Default }),
+        );
+        assert_eq!(
+            parse_str("submit --day 3 --part 2 --input-dir inputs"),
+            Ok(Command::Submit { day: 3, part: Part::Two, answer: None, input: InputSource::Dir(PathBuf::from("inputs")) }),
+        );
+        assert!(parse_str("submit --day 3 --answer 42").is_err());
+        assert!(parse_str("submit --part 1 --answer 42").is_err());
+        assert!(parse_str("submit --day 3 --part 1 --answer 42 --input a.txt").is_err());
+    }
+}
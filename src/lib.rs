@@ -1,19 +1,72 @@
-pub mod aoc {
-    // define a trait alias
-    pub fn format_with_time<Output: std::fmt::Display, F: FnOnce() -> Output>(f: F) -> String {
-        let now = std::time::Instant::now();
-        let result = f();
-        let elapsed = now.elapsed();
-
-        format!("{} ({:}ms elapsed)", result, elapsed.as_millis())
+use std::path::PathBuf;
+
+use advent_of_code_2024::aoc::Solution;
+
+/// Every implemented day, in order. New days must be added here to be
+/// picked up by the runner.
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_3::Day3,
+    &day_4::Day4,
+    &day_5::Day5,
+    &day_6::Day6,
+    &day_7::Day7,
+    &day_8::Day8,
+    &day_9::Day9,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+];
+
+/// Git-ignored directory for everything the runner keeps between runs, such
+/// as downloaded inputs.
+pub fn state_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".aoc")
+}
+
+/// Expected answers for the bundled inputs.
+pub fn answers_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.txt")
+}
+
+/// The checked out git revision, marked `-dirty` when there are uncommitted
+/// changes, or `unknown` outside a git checkout.
+pub fn revision() -> String {
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match (git(&["rev-parse", "--short", "HEAD"]), git(&["status", "--porcelain"])) {
+        (Some(revision), Some(status)) if !status.is_empty() => format!("{revision}-dirty"),
+        (Some(revision), _) => revision,
+        (None, _) => "unknown".to_string(),
+    }
+}
+
+pub fn find(day: u32) -> Option<&'static dyn Solution> {
+    SOLUTIONS.iter().copied().find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered_and_unique() {
+        for pair in SOLUTIONS.windows(2) {
+            assert!(pair[0].day() < pair[1].day());
+        }
     }
 
-    pub fn run_parts<T: std::fmt::Display, F1: FnOnce(&str) -> T, F2: FnOnce(&str) -> T>(
-        input: &str,
-        part_1: F1,
-        part_2: F2,
-    ) {
-        println!("part_1: {}", format_with_time(|| part_1(input)));
-        println!("part_2: {}", format_with_time(|| part_2(input)));
+    #[test]
+    fn test_find() {
+        assert_eq!(find(6).map(|solution| solution.day()), Some(6));
+        assert!(find(26).is_none());
     }
 }
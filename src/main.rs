@@ -1,3 +1,419 @@
-fn main() {
-    println!("Hello, world!");
+mod cli;
+mod scaffold;
+mod watch;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use advent_of_code_2024::aoc::{Part, Solution};
+use advent_of_code_2024::aoc::answers::{Check, KnownAnswers};
+use advent_of_code_2024::aoc::alloc::{self, AllocStats};
+use advent_of_code_2024::aoc::bench::{self, BenchConfig, Stats};
+use advent_of_code_2024::aoc::history::{Comparison, History, Record};
+use advent_of_code_2024::aoc::client::Client;
+use advent_of_code_2024::aoc::submit::Ledger;
+use advent_of_code_2024::aoc::input::{self, InputSource};
+use advent_of_code_2024::aoc::log;
+use advent_of_code_2024::aoc::{player, pool};
+use advent_of_code_2024::aoc::report::{Format, PartResult, Reporter, Status};
+use advent_of_code_2024::aoc::export::{self, ImageOptions};
+use advent_of_code_2024::aoc::visualize::{Palette, Recorder};
+use aoc_runner::SOLUTIONS;
+use cli::{Command, Compare, Export};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (level, args) = cli::verbosity(&args);
+    if let Err(message) = log::init(level) {
+        eprintln!("error: {message}");
+        return ExitCode::FAILURE;
+    }
+    let command = match cli::parse(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {message}\n\n{}", cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let result = match command {
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+        Command::List => {
+            list();
+            Ok(())
+        }
+        Command::Run { day, part, input, format, timeout } => run(day, part, &input, format, timeout),
+        Command::RunAll { input, format, jobs, budget, timeout } => run_all(&input, format, jobs, budget, timeout),
+        Command::Bench { day, part, config, input, compare } => bench(day, part, &config, &input, compare),
+        Command::Verify { day, answers } => verify(day, answers.unwrap_or_else(aoc_runner::answers_path)),
+        Command::Fetch { day } => fetch(day),
+        Command::Submit { day, part, answer, input } => submit(day, part, answer, &input),
+        Command::NewDay { day, title } => new_day(day, title),
+        Command::Watch { day, input, interval } => watch(day, &input, interval),
+        Command::Animate { day, input, fps, every, limit, export, cell_size, colors } => {
+            let recorder = Recorder::new().every(every as usize).limit(limit as usize);
+            animate(day, &input, recorder, fps, export, cell_size, &colors)
+        }
+        Command::Svg { day, input, out, cell_size } => svg(day, &input, out, cell_size),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn list() {
+    for solution in SOLUTIONS {
+        println!("day {:>2}: {}", solution.day(), solution.title());
+    }
+}
+
+fn run(day: u32, part: Option<Part>, source: &InputSource, format: Format, timeout: Option<Duration>) -> Result<(), String> {
+    let solution = aoc_runner::find(day).ok_or(format!("day {day} is not implemented"))?;
+    let input: Arc<str> = input::load(solution, source).map_err(|e| e.to_string())?.into();
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut failed = 0;
+    let mut reporter = Reporter::new(std::io::stdout().lock(), format);
+    reporter.start_day(solution).map_err(|e| e.to_string())?;
+    for part in parts {
+        let result = PartResult::solve_within(solution, part, &input, timeout);
+        if !result.is_ok() {
+            failed += 1;
+        }
+        reporter.record(&result).map_err(|e| e.to_string())?;
+    }
+    reporter.finish().map_err(|e| e.to_string())?;
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{failed} part(s) failed")),
+    }
+}
+
+/// Runs every day on a pool of threads, one day per job. Days without an
+/// input or with a failing or timed out part are reported instead of
+/// stopping the rest.
+fn run_all(
+    source: &InputSource,
+    format: Format,
+    jobs: Option<usize>,
+    budget: Option<Duration>,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let start = Instant::now();
+    let days = pool::map(SOLUTIONS, jobs.unwrap_or_else(pool::default_jobs), |&solution| {
+        match input::load(solution, source) {
+            Ok(input) => {
+                let input: Arc<str> = input.into();
+                Part::ALL.map(|part| PartResult::solve_within(solution, part, &input, timeout))
+            }
+            Err(e) => Part::ALL.map(|part| PartResult::error(solution.day(), part, e.to_string())),
+        }
+    });
+    let wall = start.elapsed();
+
+    let results: Vec<&PartResult> = days.iter().flatten().collect();
+    if format == Format::Text {
+        print_summary(&results);
+    } else {
+        let mut reporter = Reporter::new(std::io::stdout().lock(), format);
+        for &result in &results {
+            reporter.record(result).map_err(|e| e.to_string())?;
+        }
+        reporter.finish().map_err(|e| e.to_string())?;
+    }
+
+    // the summary line goes to stderr for structured formats, to keep stdout parseable
+    let total: Duration = results.iter().map(|result| result.duration).sum();
+    let mut summary = format!("total {} wall, {} across parts", bench::format_duration(wall), bench::format_duration(total));
+    let over_budget = budget.filter(|&budget| wall > budget);
+    if let Some(budget) = budget {
+        let verdict = match over_budget {
+            Some(_) => format!("over by {}", bench::format_duration(wall - budget)),
+            None => "within budget".to_string(),
+        };
+        summary += &format!(" (budget {}: {verdict})", bench::format_duration(budget));
+    }
+    match format {
+        Format::Text => println!("{summary}"),
+        _ => eprintln!("{summary}"),
+    }
+
+    let failed = days.iter().filter(|parts| parts.iter().any(|result| !result.is_ok())).count();
+    match (failed, over_budget) {
+        (0, None) => Ok(()),
+        (0, Some(_)) => Err("over the time budget".to_string()),
+        _ => Err(format!("{failed} day(s) failed or could not be run")),
+    }
+}
+
+fn print_summary(results: &[&PartResult]) {
+    let allocations = match alloc::is_enabled() {
+        true => format!("  {:>8}  {:>10}  {:>10}", "allocs", "allocated", "peak"),
+        false => String::new(),
+    };
+    println!("{:>3}  {:<6}  {:<18}  {:>12}{allocations}  status", "day", "part", "answer", "time");
+    for result in results {
+        let answer = result.answer.as_ref().map_or("-", |answer| answer.as_str());
+        let time = bench::format_duration(result.duration);
+        let status = match (&result.status, result.status.message()) {
+            (Status::Timeout, _) => "TIMEOUT".to_string(),
+            (status, Some(message)) => format!("{}: {message}", status.name()),
+            (status, None) => status.name().to_string(),
+        };
+        let allocations = match (alloc::is_enabled(), result.allocations) {
+            (true, Some(stats)) => format_allocations(stats),
+            (true, None) => format!("  {:>8}  {:>10}  {:>10}", "-", "-", "-"),
+            (false, _) => String::new(),
+        };
+        println!("{:>3}  {:<6}  {:<18}  {time:>12}{allocations}  {status}", result.day, result.part, answer);
+    }
+    println!();
+}
+
+fn bench(
+    day: Option<u32>,
+    part: Option<Part>,
+    config: &BenchConfig,
+    source: &InputSource,
+    compare: Option<Compare>,
+) -> Result<(), String> {
+    let solutions: Vec<&dyn Solution> = match day {
+        Some(day) => vec![aoc_runner::find(day).ok_or(format!("day {day} is not implemented"))?],
+        None => SOLUTIONS.to_vec(),
+    };
+    let parts = match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    let mut history = History::load(aoc_runner::state_dir().join("bench_history.tsv")).map_err(|e| e.to_string())?;
+    let revision = aoc_runner::revision();
+
+    let mut regressions = 0;
+    let mut measure = |day: u32, step: &str, stats: Stats, allocations: Option<AllocStats>| -> Result<(), String> {
+        let comparison = compare.as_ref().map(|compare| {
+            let baseline = history.baseline(day, step, &revision, compare.baseline.as_deref());
+            let comparison = baseline.map(|baseline| Comparison { baseline: baseline.stats.median, current: stats.median });
+            match comparison {
+                Some(comparison) if comparison.is_regression(compare.threshold_percent as f64) => {
+                    regressions += 1;
+                    format!("{:+.1}% SLOWER than {}", comparison.change(), baseline.unwrap().revision)
+                }
+                Some(comparison) => format!("{:+.1}% vs {}", comparison.change(), baseline.unwrap().revision),
+                None => "no baseline".to_string(),
+            }
+        });
+        print_stats(day, step, &stats, allocations, comparison.as_deref().unwrap_or(""));
+
+        let at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let record = Record { day, step: step.to_string(), revision: revision.clone(), stats, at };
+        history.record(record).map_err(|e| e.to_string())
+    };
+
+    let mut header = format!("{:>3}  {:<6}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}", "day", "step", "iters", "min", "median", "p95", "mean");
+    if alloc::is_enabled() {
+        header += &format!("  {:>8}  {:>10}  {:>10}", "allocs", "allocated", "peak");
+    }
+    println!("{header}");
+    for solution in solutions {
+        let input = input::load(solution, source).map_err(|e| e.to_string())?;
+        // heap usage comes from one untimed run, so counting does not skew the timings
+        if let (Some(_), allocations) = alloc::measure(|| solution.parse(&input)) {
+            measure(solution.day(), "parse", bench::bench(config, || solution.parse(&input)), allocations)?;
+        }
+        for &part in &parts {
+            // an error would only be timed as a fast failure, so stop here instead
+            let (result, allocations) = alloc::measure(|| solution.solve(part, &input));
+            result.map_err(|e| format!("day {} {part}: {e}", solution.day()))?;
+            let stats = bench::bench(config, || solution.solve(part, &input));
+            measure(solution.day(), &part.to_string(), stats, allocations)?;
+        }
+    }
+
+    match (compare, regressions) {
+        (Some(compare), 1..) => Err(format!("{regressions} step(s) got more than {}% slower", compare.threshold_percent)),
+        _ => Ok(()),
+    }
+}
+
+fn print_stats(day: u32, step: &str, stats: &Stats, allocations: Option<AllocStats>, comparison: &str) {
+    let [min, median, p95, mean] = [stats.min, stats.median, stats.p95, stats.mean].map(bench::format_duration);
+    let mut line = format!("{day:>3}  {step:<6}  {:>5}  {min:>10}  {median:>10}  {p95:>10}  {mean:>10}", stats.iterations);
+    if let Some(allocations) = allocations {
+        line += &format_allocations(allocations);
+    }
+    println!("{}", format!("{line}  {comparison}").trim_end());
+}
+
+fn format_allocations(stats: AllocStats) -> String {
+    let [bytes, peak] = [stats.bytes, stats.peak].map(alloc::format_bytes);
+    format!("  {:>8}  {bytes:>10}  {peak:>10}", stats.allocations)
+}
+
+/// Runs days against their bundled inputs and compares with the known
+/// answers. Fails on any mismatch or day that could not be run.
+fn verify(day: Option<u32>, answers_path: PathBuf) -> Result<(), String> {
+    let known = KnownAnswers::load(&answers_path)?;
+    let solutions: Vec<&dyn Solution> = match day {
+        Some(day) => vec![aoc_runner::find(day).ok_or(format!("day {day} is not implemented"))?],
+        None => SOLUTIONS.to_vec(),
+    };
+
+    let (mut failed, mut missing) = (0, 0);
+    println!("{:>3}  {:<6}  {:<18}  {:<18}  status", "day", "part", "answer", "expected");
+    for solution in solutions {
+        let input = match input::load(solution, &InputSource::Default) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{:>3}  {:<6}  {e}", solution.day(), "-");
+                failed += 1;
+                continue;
+            }
+        };
+        for part in Part::ALL {
+            let answer = match solution.solve(part, &input) {
+                Ok(answer) => answer,
+                Err(e) => {
+                    println!("{:>3}  {:<6}  error: {e}", solution.day(), part);
+                    failed += 1;
+                    continue;
+                }
+            };
+            let (expected, status) = match known.check(solution.day(), part, &answer) {
+                Check::Pass => (answer.to_string(), "pass"),
+                Check::Fail { expected } => {
+                    failed += 1;
+                    (expected, "FAIL")
+                }
+                Check::Missing => {
+                    missing += 1;
+                    ("?".to_string(), "missing")
+                }
+            };
+            println!("{:>3}  {:<6}  {:<18}  {:<18}  {status}", solution.day(), part, answer, expected);
+        }
+    }
+
+    println!();
+    println!("{failed} failed, {missing} without a known answer");
+    match failed {
+        0 => Ok(()),
+        _ => Err(format!("{failed} check(s) failed")),
+    }
+}
+
+fn fetch(day: Option<u32>) -> Result<(), String> {
+    let client = Client::from_env(aoc_runner::state_dir()).map_err(|e| e.to_string())?;
+    let days = match day {
+        Some(day) => vec![day],
+        None => SOLUTIONS.iter().map(|solution| solution.day()).collect(),
+    };
+    for day in days {
+        client.fetch_input(day).map_err(|e| format!("day {day}: {e}"))?;
+        println!("day {day}: {}", client.input_cache_path(day).display());
+    }
+    Ok(())
+}
+
+fn submit(day: u32, part: Part, answer: Option<String>, source: &InputSource) -> Result<(), String> {
+    let answer = match answer {
+        Some(answer) => answer.into(),
+        None => {
+            let solution = aoc_runner::find(day).ok_or(format!("day {day} is not implemented"))?;
+            let input = input::load(solution, source).map_err(|e| e.to_string())?;
+            solution.solve(part, &input).map_err(|e| format!("day {day} {part}: {e}"))?
+        }
+    };
+
+    let client = Client::from_env(aoc_runner::state_dir()).map_err(|e| e.to_string())?;
+    let mut ledger = Ledger::load(aoc_runner::state_dir().join("ledger.tsv")).map_err(|e| e.to_string())?;
+    let outcome = client.submit(&mut ledger, day, part, &answer).map_err(|e| e.to_string())?;
+    println!("day {day} {part}: {answer} is {outcome}");
+    Ok(())
+}
+
+fn new_day(day: u32, title: Option<String>) -> Result<(), String> {
+    let title = title.unwrap_or_else(|| format!("Day {day}"));
+    let dir = scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), day, &title)?;
+    println!("created {}", dir.display());
+    println!("next: aoc fetch --day {day}, then fill in part_1 and part_2 in src/lib.rs");
+    Ok(())
+}
+
+fn watch(day: u32, source: &InputSource, interval: Duration) -> Result<(), String> {
+    let solution = aoc_runner::find(day).ok_or(format!("day {day} is not implemented"))?;
+    let input = input::path(solution, source).ok_or("watch cannot read stdin")?;
+    watch::watch(Path::new(env!("CARGO_MANIFEST_DIR")), day, &input, interval)
+}
+
+fn animate(
+    day: u32,
+    source: &InputSource,
+    mut recorder: Recorder,
+    fps: u32,
+    export: Option<Export>,
+    cell_size: u32,
+    colors: &Palette,
+) -> Result<(), String> {
+    let solution = aoc_runner::find(day).ok_or(format!("day {day} is not implemented"))?;
+    let input = input::load(solution, source).map_err(|e| e.to_string())?;
+    let mut simulation = solution
+        .visualize(&input)
+        .ok_or(format!("day {day} has no animation"))?
+        .map_err(|e| format!("day {day}: {e}"))?;
+
+    simulation.record(&mut recorder);
+    if recorder.dropped() > 0 {
+        eprintln!("left out {} frames; pass --every or --limit to change which are kept", recorder.dropped());
+    }
+    let palette = simulation.palette().overlay(colors);
+    let options = ImageOptions { cell_size, delay: Duration::from_secs_f64(1.0 / fps as f64), ..ImageOptions::default() };
+    match export {
+        None => player::play(recorder.frames(), &palette, fps as f64).map_err(|e| e.to_string()),
+        Some(Export::Png(dir)) => {
+            let paths = export::write_png_sequence(recorder.frames(), &palette, &options, &dir).map_err(|e| e.to_string())?;
+            println!("wrote {} frames to {}", paths.len(), dir.display());
+            Ok(())
+        }
+        Some(Export::Gif(path)) => {
+            let file = std::fs::File::create(&path).map_err(|e| format!("failed to create {}: {e}", path.display()))?;
+            let mut out = std::io::BufWriter::new(file);
+            export::write_gif(recorder.frames(), &palette, &options, &mut out).map_err(|e| e.to_string())?;
+            std::io::Write::flush(&mut out).map_err(|e| e.to_string())?;
+            println!("wrote {} frames to {}", recorder.frames().len(), path.display());
+            Ok(())
+        }
+    }
+}
+
+fn svg(day: u32, source: &InputSource, out: Option<PathBuf>, cell_size: Option<u32>) -> Result<(), String> {
+    let solution = aoc_runner::find(day).ok_or(format!("day {day} is not implemented"))?;
+    let input = input::load(solution, source).map_err(|e| e.to_string())?;
+    let mut svg = solution
+        .svg(&input)
+        .ok_or(format!("day {day} has no drawing"))?
+        .map_err(|e| format!("day {day}: {e}"))?;
+    if let Some(cell_size) = cell_size {
+        svg = svg.cell_size(cell_size);
+    }
+    match out {
+        None => print!("{svg}"),
+        Some(path) => {
+            std::fs::write(&path, svg.to_string()).map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+            println!("wrote {}", path.display());
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,260 @@
+use std::path::{Path, PathBuf};
+
+/// Creates `crates/day_N` under `root` and registers it as a workspace member,
+/// a dependency of the runner and an entry in `SOLUTIONS`. Nothing is written
+/// if any of the edits would fail.
+pub fn new_day(root: &Path, day: u32, title: &str) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {day}"));
+    }
+    let dir = root.join("crates").join(format!("day_{day}"));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let registry_path = root.join("src").join("lib.rs");
+    let manifest = read(&manifest_path)?;
+    let manifest = add_dependency(&manifest, day)?;
+    let manifest = add_workspace_member(&manifest, day)?;
+    let registry = add_registry_entry(&read(&registry_path)?, day)?;
+
+    let src = dir.join("src");
+    std::fs::create_dir_all(&src).map_err(|e| format!("failed to create {}: {e}", src.display()))?;
+    write(&dir.join("Cargo.toml"), &day_manifest(day))?;
+    write(&src.join("lib.rs"), &day_lib(day, title))?;
+    write(&src.join("main.rs"), &day_main(day))?;
+    write(&src.join("example.txt"), "")?;
+    write(&src.join("input.txt"), "")?;
+    write(&manifest_path, &manifest)?;
+    write(&registry_path, &registry)?;
+    Ok(dir)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("failed to read {}: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    std::fs::write(path, contents).map_err(|e| format!("failed to write {}: {e}", path.display()))
+}
+
+/// The day number in a `day_N` dependency line or registry entry.
+fn day_of(line: &str) -> Option<u32> {
+    let rest = line.trim().trim_start_matches('&').strip_prefix("day_")?;
+    let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+/// Inserts `line` after the last line for a day before `day`, keeping days in
+/// order. `contents` must already list at least one day.
+fn insert_in_order(contents: &str, day: u32, line: String, is_day_line: impl Fn(&str) -> bool) -> Result<String, String> {
+    let mut lines: Vec<String> = contents.lines().map(String::from).collect();
+    let days: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| is_day_line(line))
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+    if days.iter().any(|&(_, d)| d == day) {
+        return Err(format!("day {day} is already listed"));
+    }
+    let index = match days.iter().rev().find(|&&(_, d)| d < day) {
+        Some(&(i, _)) => i + 1,
+        None => days.first().ok_or("no existing days to insert next to")?.0,
+    };
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn add_dependency(manifest: &str, day: u32) -> Result<String, String> {
+    let line = format!(r#"day_{day} = {{ version = "0.1.0", path = "crates/day_{day}" }}"#);
+    insert_in_order(manifest, day, line, |line| line.starts_with("day_") && line.contains("path ="))
+}
+
+/// Members are kept on one line, sorted the way cargo sorts them.
+fn add_workspace_member(manifest: &str, day: u32) -> Result<String, String> {
+    let member = format!("crates/day_{day}");
+    let mut found = false;
+    let mut lines = Vec::new();
+    for line in manifest.lines() {
+        let Some(list) = line.trim().strip_prefix("members = [").and_then(|rest| rest.strip_suffix(']')) else {
+            lines.push(line.to_string());
+            continue;
+        };
+        let mut members: Vec<String> = list
+            .split(',')
+            .map(|member| member.trim().trim_matches('"').to_string())
+            .filter(|member| !member.is_empty())
+            .collect();
+        if members.contains(&member) {
+            return Err(format!("{member} is already a workspace member"));
+        }
+        members.push(member.clone());
+        members.sort();
+        let members: Vec<String> = members.iter().map(|member| format!("\"{member}\"")).collect();
+        lines.push(format!("members = [{}]", members.join(", ")));
+        found = true;
+    }
+    match found {
+        true => Ok(lines.join("\n") + "\n"),
+        false => Err("no single-line 'members = [...]' in Cargo.toml".to_string()),
+    }
+}
+
+fn add_registry_entry(registry: &str, day: u32) -> Result<String, String> {
+    insert_in_order(registry, day, format!("    &day_{day}::Day{day},"), |line| line.trim().starts_with("&day_"))
+}
+
+fn day_manifest(day: u32) -> String {
+    format!(
+        r#"[package]
+name = "day_{day}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+advent_of_code_2024 = {{ version = "0.1.0", path = "../aoc" }}
+"#
+    )
+}
+
+fn day_lib(day: u32, title: &str) -> String {
+    let title = title.replace('\\', "\\\\").replace('"', "\\\"");
+    format!(
+        r#"use advent_of_code_2024::aoc::{{AocError, Answer, Solution}};
+use std::path::Path;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    fn day(&self) -> u32 {{
+        {day}
+    }}
+
+    fn title(&self) -> &'static str {{
+        "{title}"
+    }}
+
+    fn input_path(&self) -> &'static Path {{
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/input.txt"))
+    }}
+
+    fn part_1(&self, input: &str) -> Result<Answer, AocError> {{
+        part_1(input).map(Answer::from)
+    }}
+
+    fn part_2(&self, input: &str) -> Result<Answer, AocError> {{
+        part_2(input).map(Answer::from)
+    }}
+}}
+
+fn part_1(_input: &str) -> Result<i64, AocError> {{
+    Err(AocError::NoSolution("part 1 is not solved yet".to_string()))
+}}
+
+fn part_2(_input: &str) -> Result<i64, AocError> {{
+    Err(AocError::NoSolution("part 2 is not solved yet".to_string()))
+}}
+
+// advent_of_code_2024::examples! {{
+//     Day{day},
+//     "example.txt" => {{ part_1: 0 }},
+// }}
+"#
+    )
+}
+
+fn day_main(day: u32) -> String {
+    format!(
+        r#"use std::process::ExitCode;
+
+use advent_of_code_2024::aoc;
+use day_{day}::Day{day};
+
+fn main() -> ExitCode {{
+    aoc::run_solution(&Day{day})
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"[package]
+name = "aoc_runner"
+
+[dependencies]
+advent_of_code_2024 = { version = "0.1.0", path = "crates/aoc" }
+day_1 = { version = "0.1.0", path = "crates/day_1" }
+day_2 = { version = "0.1.0", path = "crates/day_2" }
+day_10 = { version = "0.1.0", path = "crates/day_10" }
+
+[workspace]
+members = ["crates/aoc", "crates/day_1", "crates/day_10", "crates/day_2"]
+"#;
+
+    const REGISTRY: &str = "pub const SOLUTIONS: &[&dyn Solution] = &[
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_10::Day10,
+];
+";
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = add_dependency(MANIFEST, 3).unwrap();
+        assert!(manifest.contains(concat!(
+            "day_2 = { version = \"0.1.0\", path = \"crates/day_2\" }\n",
+            "day_3 = { version = \"0.1.0\", path = \"crates/day_3\" }\n",
+            "day_10 ",
+        )));
+        assert!(add_dependency(MANIFEST, 10).is_err());
+    }
+
+    #[test]
+    fn test_add_workspace_member() {
+        let manifest = add_workspace_member(MANIFEST, 3).unwrap();
+        assert!(manifest.contains(r#"members = ["crates/aoc", "crates/day_1", "crates/day_10", "crates/day_2", "crates/day_3"]"#));
+        assert!(add_workspace_member(MANIFEST, 2).is_err());
+        assert!(add_workspace_member("[workspace]\n", 3).is_err());
+    }
+
+    #[test]
+    fn test_add_registry_entry() {
+        assert_eq!(
+            add_registry_entry(REGISTRY, 11).unwrap(),
+            "pub const SOLUTIONS: &[&dyn Solution] = &[
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_10::Day10,
+    &day_11::Day11,
+];
+",
+        );
+        assert!(add_registry_entry(REGISTRY, 1).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        std::fs::write(root.join("src").join("lib.rs"), REGISTRY).unwrap();
+
+        let dir = new_day(&root, 13, "Claw \"Contraption\"").unwrap();
+        let lib = std::fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        let registry = std::fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        let second = new_day(&root, 13, "again");
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(dir, root.join("crates/day_13"));
+        assert!(lib.contains("pub struct Day13;"));
+        assert!(lib.contains(r#""Claw \"Contraption\"""#));
+        assert!(registry.contains("    &day_10::Day10,\n    &day_13::Day13,\n"));
+        assert!(second.is_err());
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+
+use advent_of_code_2024::aoc::bench::format_duration;
+use advent_of_code_2024::aoc::report::PartResult;
+
+/// Modification time and size of every file under the watched paths.
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// Rebuilds and re-runs day `day` whenever its crate's sources or `input`
+/// change, printing each run's results next to the previous ones. Runs until
+/// interrupted.
+pub fn watch(root: &Path, day: u32, input: &Path, interval: Duration) -> Result<(), String> {
+    let crate_dir = root.join("crates").join(format!("day_{day}"));
+    if !crate_dir.is_dir() {
+        return Err(format!("{} does not exist", crate_dir.display()));
+    }
+    let watched = [crate_dir.join("src"), crate_dir.join("Cargo.toml"), input.to_path_buf()];
+    println!("watching {} and {}, press Ctrl-C to stop", crate_dir.display(), input.display());
+
+    let mut previous: Option<Vec<PartResult>> = None;
+    let mut seen = snapshot(&watched);
+    loop {
+        match run_day(root, day, input) {
+            Ok(results) => {
+                print!("{}", comparison(previous.as_deref(), &results));
+                previous = Some(results);
+            }
+            Err(e) => eprintln!("{e}; keeping the previous results"),
+        }
+
+        let (changed, now) = wait_for_change(&watched, &seen, interval);
+        seen = now;
+        println!("\n{} changed, rebuilding", changed.display());
+    }
+}
+
+/// Polls until something differs from `seen`, then until the files stop
+/// changing, so an editor saving in several steps causes a single rebuild.
+fn wait_for_change(watched: &[PathBuf], seen: &Snapshot, interval: Duration) -> (PathBuf, Snapshot) {
+    loop {
+        std::thread::sleep(interval);
+        let mut now = snapshot(watched);
+        let Some(changed) = first_change(seen, &now) else {
+            continue;
+        };
+        loop {
+            std::thread::sleep(interval);
+            let next = snapshot(watched);
+            if next == now {
+                return (changed, now);
+            }
+            now = next;
+        }
+    }
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    fn visit(path: &Path, files: &mut Snapshot) {
+        let Ok(metadata) = std::fs::metadata(path) else {
+            return;
+        };
+        if metadata.is_dir() {
+            for entry in std::fs::read_dir(path).into_iter().flatten().flatten() {
+                visit(&entry.path(), files);
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            files.insert(path.to_path_buf(), (modified, metadata.len()));
+        }
+    }
+
+    let mut files = Snapshot::new();
+    for path in paths {
+        visit(path, &mut files);
+    }
+    files
+}
+
+/// A file that was added, removed or modified between two snapshots.
+fn first_change(before: &Snapshot, after: &Snapshot) -> Option<PathBuf> {
+    let modified = after.iter().find(|&(path, stamp)| before.get(path) != Some(stamp));
+    let removed = before.keys().find(|path| !after.contains_key(*path));
+    modified.map(|(path, _)| path).or(removed).cloned()
+}
+
+/// Builds and runs the day binary with cargo, reading its results back from
+/// CSV output. Compiler messages pass straight through to stderr.
+fn run_day(root: &Path, day: u32, input: &Path) -> Result<Vec<PartResult>, String> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.current_dir(root).args(["run", "--quiet", "-p", &format!("day_{day}")]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    let output = command
+        .arg("--")
+        .args(["--format", "csv"])
+        .arg(input)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("failed to run cargo: {e}"))?;
+
+    // failing parts still report, so only a missing report means the build failed
+    match PartResult::from_csv(&String::from_utf8_lossy(&output.stdout)) {
+        Ok(results) if !results.is_empty() => Ok(results),
+        _ => Err(format!("day {day} did not build or run ({})", output.status)),
+    }
+}
+
+/// The previous and current answers and timings side by side, followed by
+/// any errors from the current run.
+fn comparison(previous: Option<&[PartResult]>, current: &[PartResult]) -> String {
+    let cell = |result: Option<&PartResult>| match result {
+        Some(result) => {
+            let answer = result.answer.as_ref().map_or(result.status.name(), |answer| answer.as_str());
+            (answer.to_string(), format_duration(result.duration))
+        }
+        None => ("-".to_string(), "-".to_string()),
+    };
+
+    let mut table = format!("{:<6}  {:<18}  {:>10}  {:<18}  {:>10}\n", "part", "previous", "time", "current", "time");
+    for result in current {
+        let before = previous.and_then(|previous| previous.iter().find(|before| before.part == result.part));
+        let ((old_answer, old_time), (answer, time)) = (cell(before), cell(Some(result)));
+        let note = match before {
+            Some(before) if before.answer != result.answer => "changed",
+            _ => "",
+        };
+        let line = format!("{:<6}  {old_answer:<18}  {old_time:>10}  {answer:<18}  {time:>10}  {note}", result.part);
+        table += line.trim_end();
+        table.push('\n');
+    }
+    for result in current {
+        if let Some(message) = result.status.message() {
+            table += &format!("{}: {}: {message}\n", result.part, result.status.name());
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code_2024::aoc::Part;
+    use advent_of_code_2024::aoc::report::Status;
+
+    fn ok(part: Part, answer: i64, millis: u64) -> PartResult {
+        PartResult {
+            day: 9,
+            part,
+            answer: Some(answer.into()),
+            duration: Duration::from_millis(millis),
+            status: Status::Ok,
+            allocations: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_changes() {
+        let dir = std::env::temp_dir().join(format!("aoc_watch_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("src/lib.rs"), "fn main() {}").unwrap();
+        let watched = [dir.join("src"), dir.join("input.txt")];
+
+        let before = snapshot(&watched);
+        std::fs::write(dir.join("input.txt"), "1 2 3").unwrap();
+        let added = snapshot(&watched);
+        std::fs::write(dir.join("src/lib.rs"), "fn main() { }").unwrap();
+        let modified = snapshot(&watched);
+        std::fs::remove_file(dir.join("input.txt")).unwrap();
+        let removed = snapshot(&watched);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(before.len(), 1);
+        assert_eq!(first_change(&before, &before), None);
+        assert_eq!(first_change(&before, &added), Some(dir.join("input.txt")));
+        assert_eq!(first_change(&added, &modified), Some(dir.join("src/lib.rs")));
+        assert_eq!(first_change(&modified, &removed), Some(dir.join("input.txt")));
+    }
+
+    #[test]
+    fn test_comparison() {
+        let previous = [ok(Part::One, 1928, 2), ok(Part::Two, 2858, 40)];
+        let current = [ok(Part::One, 1928, 1), PartResult::error(9, Part::Two, "no solution: gave up")];
+        assert_eq!(
+            comparison(Some(&previous), &current),
+            concat!(
+                "part    previous                  time  current                   time\n",
+                "part_1  1928                   2.000ms  1928                   1.000ms\n",
+                "part_2  2858                  40.000ms  error                      0ns  changed\n",
+                "part_2: error: no solution: gave up\n",
+            ),
+        );
+        assert!(comparison(None, &previous).contains("part_1  -                            -  1928"));
+    }
+}